@@ -16,9 +16,6 @@ pub trait Foo {
 
 macro_rules! impl_tuple_foo {
     ($(#[$meta:meta])* $($name: ident),*) => {
-        #[allow(unused_variables)]
-        #[allow(non_snake_case)]
-        #[allow(clippy::unused_unit)]
         $(#[$meta])*
         impl<$($name: Foo),*> Foo for ($($name,)*) {
             const FOO_HARDER: bool = true $(&& $name::FOO_HARDER)*;
@@ -31,6 +28,7 @@ macro_rules! impl_tuple_foo {
 }
 
 all_tuples!(
+    #[allow(unused_variables, non_snake_case, clippy::unused_unit)]
     #[doc(fake_variadic)]
     impl_tuple_foo,
     0,
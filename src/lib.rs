@@ -17,39 +17,585 @@ unsynn! {
     keyword KDoc = "doc";
     keyword KFakeVariadic = "fake_variadic";
 
-    /// `all_tuples!(#[doc(fake_variadic)] some_macro, 1, 16, P, Q, ..)`
+    /// `all_tuples!(#[doc(fake_variadic)] some_macro, 1..=16, P, Q, ..; [extra, tokens]; options)`
+    /// or `all_tuples!(#[doc(fake_variadic)] 1..=16, P, Q, ..; options => { .. })`
     struct AllTuplesParsed {
-        fake_variadic: Option<FakeVariadicAttr>,
-        macro_ident: Ident,
-        _comma1: Comma,
-        start: LiteralInteger,
+        /// `#[doc(fake_variadic)]` or any other attribute, which is forwarded as is.
+        attrs: Any<Cons<Pound, BracketGroup>>,
+        macro_path: Option<Cons<MacroPathParsed, Comma>>,
+        arities: AritiesParsed,
+        _comma: Comma,
+        idents: CommaDelimitedVec<BaseIdentParsed>,
+        clauses: Any<Cons<Semicolon, ClauseParsed>>,
+        template: Option<Cons<FatArrow, BraceGroup>>,
+    }
+
+    keyword KConst = "const";
+
+    /// `T`, `T: Clone + Send + 'static`, `'a`, `'a: 'static` or `const N: usize`
+    struct BaseIdentParsed {
+        kind: Option<Either<LifetimeTick, KConst>>,
+        ident: Ident,
+        bounds: Option<Cons<Colon, Any<BoundTokenParsed>>>,
+    }
+
+    /// A token of the bounds in [`BaseIdentParsed`], which end at a `,`, `;` or `=>` that isn't
+    /// inside of `<..>`.
+    enum BoundTokenParsed {
+        Arrow(RArrow),
+        Generics(Cons<Lt, Any<Box<GenericTokenParsed>>, Gt>),
+        Token(Cons<Except<Either<Comma, Semicolon, FatArrow>>, TokenTree>),
+    }
+
+    /// A token inside of the `<..>` of a bound, e.g. `Into<(u8, u16)>` or `Fn(u8) -> u8`
+    enum GenericTokenParsed {
+        Arrow(RArrow),
+        Generics(Cons<Lt, Any<Box<GenericTokenParsed>>, Gt>),
+        Token(Cons<Except<Gt>, TokenTree>),
+    }
+
+    /// `[extra, tokens]`, `{extra, tokens}` or `one_based, separator = "_"`
+    enum ClauseParsed {
+        Extra(Either<BracketGroup, BraceGroup>),
+        Options(CommaDelimitedVec<OptionParsed>),
+    }
+
+    /// `one_based` or `separator = "_"`
+    struct OptionParsed {
+        name: Ident,
+        /// Not followed by `=>`, which starts a template.
+        value: Option<Cons<Except<FatArrow>, Assign, TokenTree>>,
+    }
+
+    /// `all_tuples_chunked!(some_macro, 12, 0..=64, P, Q, ..)`, where everything after the chunk
+    /// size is parsed as [`AllTuplesParsed`].
+    struct AllTuplesChunkedParsed {
+        macro_path: MacroPathParsed,
+        _comma: Comma,
+        chunk: ConstExprParsed,
         _comma2: Comma,
-        end: LiteralInteger,
-        _comma3: Comma,
+        rest: TokenStream2,
+    }
+
+    /// `all_tuple_pairs!(some_macro, 0..=4, A, ..; 0..=4, B, ..; options)`
+    struct AllTuplePairsParsed {
+        macro_path: MacroPathParsed,
+        _comma: Comma,
+        first: TupleSideParsed,
+        _semicolon: Semicolon,
+        second: TupleSideParsed,
+        clauses: Any<Cons<Semicolon, ClauseParsed>>,
+    }
+
+    /// One of the tuples in [`AllTuplePairsParsed`]: `0..=4, A, ..`
+    struct TupleSideParsed {
+        arities: AritiesParsed,
+        _comma: Comma,
         idents: CommaDelimitedVec<Ident>,
     }
 
-    /// `#[doc(fake_variadic)]`
+    /// `all_subsets!(some_macro, [A, B, C])` or `all_permutations!(some_macro, [A, B, C])`
+    struct IdentSetParsed {
+        macro_path: MacroPathParsed,
+        _comma: Comma,
+        idents: BracketGroupContaining<CommaDelimitedVec<Ident>>,
+        _trailing: Option<Comma>,
+    }
+
+    keyword KCfg = "cfg";
+
+    /// `#[doc(fake_variadic)]` or `#[doc(fake_variadic, cfg = my_docs)]`
     struct FakeVariadicAttr {
         _hash: Pound,
-        _bracket: BracketGroupContaining::<(KDoc, ParenthesisGroupContaining::<KFakeVariadic>)>,
+        bracket: BracketGroupContaining::<(KDoc, ParenthesisGroupContaining::<FakeVariadicArgsParsed>)>,
+    }
+
+    /// `fake_variadic` or `fake_variadic, cfg = my_docs`
+    struct FakeVariadicArgsParsed {
+        _fake_variadic: KFakeVariadic,
+        cfg: Option<Cons<Comma, KCfg, Assign, CfgPredicateParsed>>,
+        _trailing: Option<Comma>,
+    }
+
+    /// `my_docs`, `feature = "docs"` or `any(docsrs, my_docs)`
+    struct CfgPredicateParsed {
+        name: Ident,
+        args: Option<Either<Cons<Assign, LiteralString>, ParenthesisGroup>>,
+    }
+
+    /// The start of `#[doc(fake_variadic ..)]`, which tells a malformed one apart from the
+    /// attributes that are forwarded as is.
+    struct FakeVariadicPrefix {
+        _hash: Pound,
+        _bracket: BracketGroupContaining::<(KDoc, ParenthesisGroupContaining::<(KFakeVariadic, TokenStream2)>, TokenStream2)>,
+    }
+
+    /// `impl_foo`, `crate::impls::impl_foo`, `::some_crate::impl_foo` or `$crate::__impl_foo`
+    enum MacroPathParsed {
+        Path {
+            leading: Option<PathSep>,
+            segments: PathSepDelimitedVec<Ident, TrailingDelimiter::Forbidden, 1>,
+        },
+        /// A path that was passed through a `macro_rules!` `$callback:path` fragment.
+        Invisible(NoneGroupContaining<Box<MacroPathParsed>>),
+    }
+
+    keyword KRange = "range";
+    keyword KOver = "over";
+
+    keyword KStep = "step";
+
+    /// `0..=15`, `0..16` or `0..=16 step 2`
+    struct RangeParsed {
+        start: ConstExprParsed,
+        limits: Either<DotDotEq, DotDot>,
+        end: ConstExprParsed,
+        step: Option<Cons<KStep, ConstExprParsed>>,
+    }
+
+    /// `0..=15`, `0..=16 step 2`, `[0, 1, 2, 4, 8..=16]` or the positional `0, 15`
+    enum AritiesParsed {
+        Range(RangeParsed),
+        List(BracketGroupContaining<CommaDelimitedVec<ArityEntryParsed>>),
+        Positional {
+            start: ConstExprParsed,
+            _comma: Comma,
+            end: ConstExprParsed,
+        },
+        /// A range that was passed through a `macro_rules!` `$range:expr` fragment.
+        Invisible(NoneGroupContaining<Box<AritiesParsed>>),
+    }
+
+    /// An entry of the list in [`AritiesParsed`], with attributes for all of its arities:
+    /// `4`, `8..=16` or `#[cfg(feature = "large-tuples")] 17..=32`
+    struct ArityEntryParsed {
+        attrs: Any<Cons<Pound, BracketGroup>>,
+        arity: ArityParsed,
+    }
+
+    /// `4` or `8..=16`
+    enum ArityParsed {
+        Range(RangeParsed),
+        Single(ConstExprParsed),
+    }
+
+    /// `16`, `MAX - 1` or `8 * 2`, with the usual precedence
+    #[derive(Clone)]
+    struct ConstExprParsed {
+        first: ConstTermParsed,
+        rest: Any<Cons<Either<Plus, Minus>, ConstTermParsed>>,
+    }
+
+    /// `8`, `8 * 2`, `8 / 2` or `8 % 2`
+    #[derive(Clone)]
+    struct ConstTermParsed {
+        first: ConstOperandParsed,
+        rest: Any<Cons<Either<Star, Slash, Percent>, ConstOperandParsed>>,
+    }
+
+    #[derive(Clone)]
+    enum ConstOperandParsed {
+        Literal(LiteralInteger),
+        Parenthesized(ParenthesisGroupContaining<Box<ConstExprParsed>>),
+        /// An operand that was passed through a `macro_rules!` `$n:expr` fragment.
+        Invisible(NoneGroupContaining<Box<ConstExprParsed>>),
+    }
+
+    /// `#[variadic(range = 0..=15, over = T)]` or `#[variadic(range = 0..=15, over = (P, p))]`
+    struct VariadicArgsParsed {
+        _range: KRange,
+        _assign1: Assign,
+        range: AritiesParsed,
+        _comma: Comma,
+        _over: KOver,
+        _assign2: Assign,
+        over: Either<Ident, ParenthesisGroupContaining<CommaDelimitedVec<Ident>>>,
+        options: Option<Cons<Comma, CommaDelimitedVec<OptionParsed>>>,
+    }
+}
+
+impl MacroPathParsed {
+    /// Returns the path as written, without any invisible groups around it.
+    fn into_path(self) -> TokenStream2 {
+        match self {
+            MacroPathParsed::Invisible(group) => group.content.into_path(),
+            path => path.to_token_stream(),
+        }
+    }
+}
+
+impl FakeVariadicAttr {
+    /// Returns the cfg predicate under which the docs are collapsed, which is
+    /// `any(docsrs, docsrs_dep)` unless another one is given with `cfg = ..`.
+    fn cfg(self) -> TokenStream2 {
+        match self.bracket.content.1.content.cfg {
+            Some(cfg) => cfg.fourth.to_token_stream(),
+            None => quote! { any(docsrs, docsrs_dep) },
+        }
+    }
+}
+
+/// Returns the cfg predicate if `attr` is `#[doc(fake_variadic)]`, or `None` if it is an
+/// attribute that should be forwarded.
+///
+/// The predicate is a single cfg item, anything else after `cfg =` is rejected:
+///
+/// ```compile_fail
+/// # use variadics_please::all_tuples;
+/// # macro_rules! impl_foo { ($($t:tt)*) => {}; }
+/// all_tuples!(#[doc(fake_variadic, cfg = docsrs, docsrs)] impl_foo, 1, 2, T);
+/// ```
+fn fake_variadic_cfg(
+    attr: &TokenStream2,
+) -> std::result::Result<Option<TokenStream2>, TokenStream> {
+    if FakeVariadicPrefix::parse_all(&mut attr.clone().to_token_iter()).is_err() {
+        return Ok(None);
+    }
+    match FakeVariadicAttr::parse_all(&mut attr.clone().to_token_iter()) {
+        Ok(fake_variadic) => Ok(Some(fake_variadic.cfg())),
+        Err(_) => Err(span_error(
+            attr.clone().into_iter().nth(1),
+            "expected `#[doc(fake_variadic)]` or `#[doc(fake_variadic, cfg = predicate)]`",
+        )),
+    }
+}
+
+impl OptionParsed {
+    /// Checks that an option like `one_based` is given without a value.
+    fn flag(&self) -> std::result::Result<bool, TokenStream> {
+        match &self.value {
+            Some(value) => Err(span_error(
+                value.third.clone(),
+                &format!("`{}` doesn't take a value", self.name),
+            )),
+            None => Ok(true),
+        }
+    }
+
+    /// Parses the value of an option like `separator = "_"`.
+    fn value<T: Parse>(&self, expected: &str) -> std::result::Result<T, TokenStream> {
+        let value = self.value.as_ref().map(|value| &value.third);
+        value
+            .and_then(|value| T::parse_all(&mut value.to_token_iter()).ok())
+            .ok_or_else(|| {
+                span_error(
+                    value.cloned().unwrap_or_else(|| self.name.clone().into()),
+                    &format!("expected `{} = {expected}`", self.name),
+                )
+            })
+    }
+}
+
+/// The options given after a `;`, which are shared by all macros.
+#[derive(Clone, Default)]
+struct Options {
+    /// Put between the base ident and the number, e.g. `T_0` for `_`.
+    separator: String,
+    /// Start numbering at 1 instead of 0.
+    one_based: bool,
+    /// Pad the numbers with zeros, so that they all have the same width.
+    zero_pad: bool,
+    /// Name the elements `A, B, C, ..` instead of numbering them.
+    alphabetic: bool,
+    /// Resolve the generated idents at [`Span::mixed_site`] instead of the base idents.
+    mixed_site: bool,
+    /// Pass the tuple type, pattern and indices in front of the elements, see [`fragments`].
+    fragments: bool,
+    /// Separate the first or the last element from the others with a `;`.
+    split: Option<Split>,
+    /// Pass the elements of the tuple that is one element shorter in front of the elements.
+    previous: bool,
+    /// Pass the elements from last to first.
+    reverse: bool,
+    /// Repeat the base idents instead of numbering them, e.g. `T, T, T`.
+    repeat: bool,
+    /// Names that contain the arity, e.g. `"Zip#N"`.
+    names: Vec<LiteralString>,
+}
+
+/// The value of the `split` option.
+#[derive(Clone, Copy)]
+enum Split {
+    /// `split = head_tail`, e.g. `T0; T1, T2`
+    HeadTail,
+    /// `split = init_last`, e.g. `T0, T1; T2`
+    InitLast,
+}
+
+/// Whether `name` is a plain ASCII ident that [`format_ident!`] accepts.
+fn is_plain_ident(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c == '_' || c.is_ascii_alphabetic())
+        && chars.all(|c| c == '_' || c.is_ascii_alphanumeric())
+}
+
+/// Options that only make sense when there are arguments for a callback macro.
+const CALLBACK_ONLY_OPTIONS: &[&str] = &["fragments", "split", "previous", "reverse", "names"];
+
+/// Converts the options given after a `;` into [`Options`].
+///
+/// Each of the `names` has to become an ident once `#N` is replaced:
+///
+/// ```compile_fail
+/// # use variadics_please::all_tuples;
+/// # macro_rules! impl_foo { ($($t:tt)*) => {}; }
+/// all_tuples!(impl_foo, 1, 2, T; names = ["1#N"]);
+/// ```
+///
+/// The same goes for the `separator`:
+///
+/// ```compile_fail
+/// # use variadics_please::all_tuples;
+/// # macro_rules! impl_foo { ($($t:tt)*) => {}; }
+/// all_tuples!(impl_foo, 1, 2, T; separator = "²");
+/// ```
+fn options(
+    parsed: Vec<OptionParsed>,
+    callback: &Callback,
+) -> std::result::Result<Options, TokenStream> {
+    let mut options = Options::default();
+    let mut seen: Vec<Ident> = Vec::new();
+    for option in &parsed {
+        if seen.contains(&option.name) {
+            return Err(span_error(
+                option.name.clone(),
+                &format!("`{}` is given more than once", option.name),
+            ));
+        }
+        seen.push(option.name.clone());
+        match option.name.to_string().as_str() {
+            "separator" => {
+                let separator: LiteralString = option.value("\"_\"")?;
+                if !separator
+                    .as_str()
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_')
+                {
+                    return Err(span_error(
+                        separator,
+                        "`separator` should only contain ASCII letters, digits or `_`",
+                    ));
+                }
+                options.separator = separator.as_str().to_string();
+            }
+            "one_based" => options.one_based = option.flag()?,
+            "zero_pad" => options.zero_pad = option.flag()?,
+            "alphabetic" => options.alphabetic = option.flag()?,
+            "mixed_site" => options.mixed_site = option.flag()?,
+            "fragments" => options.fragments = option.flag()?,
+            "split" => {
+                let value: Ident = option.value("head_tail")?;
+                options.split = Some(match value.to_string().as_str() {
+                    "head_tail" => Split::HeadTail,
+                    "init_last" => Split::InitLast,
+                    _ => {
+                        return Err(span_error(
+                            value,
+                            "expected `split = head_tail` or `split = init_last`",
+                        ));
+                    }
+                });
+            }
+            "previous" => options.previous = option.flag()?,
+            "reverse" => options.reverse = option.flag()?,
+            "repeat" => options.repeat = option.flag()?,
+            "names" => {
+                let names: BracketGroupContaining<CommaDelimitedVec<LiteralString>> =
+                    option.value("[\"Name#N\", ..]")?;
+                for name in names.content.into_iter().map(|name| name.value) {
+                    let template = name.as_str();
+                    if template.matches("#N").count() != 1
+                        || !is_plain_ident(&template.replace("#N", "0"))
+                    {
+                        return Err(span_error(
+                            name,
+                            "expected an ident that contains `#N` once, e.g. `\"Zip#N\"`",
+                        ));
+                    }
+                    if options.names.iter().any(|other| other.as_str() == template) {
+                        let message = format!("`{template}` is given more than once");
+                        return Err(span_error(name, &message));
+                    }
+                    options.names.push(name);
+                }
+            }
+            _ => {
+                return Err(span_error(
+                    option.name.clone(),
+                    "unknown option, expected one of `separator`, `one_based`, `zero_pad`, `alphabetic`, `repeat`, `mixed_site`, `fragments`, `split`, `previous`, `reverse` or `names`",
+                ));
+            }
+        }
+        if CALLBACK_ONLY_OPTIONS.contains(&option.name.to_string().as_str())
+            && !matches!(callback, Callback::Macro(_))
+        {
+            return Err(span_error(
+                option.name.clone(),
+                &format!("`{}` can only be used with a callback macro", option.name),
+            ));
+        }
+    }
+    // Both replace the numbering, so the other naming options don't apply.
+    for (name, set) in [
+        ("alphabetic", options.alphabetic),
+        ("repeat", options.repeat),
+    ] {
+        if !set {
+            continue;
+        }
+        if let Some(other) = parsed.iter().find(|option| {
+            option.name != name
+                && ["separator", "one_based", "zero_pad", "alphabetic", "repeat"]
+                    .contains(&option.name.to_string().as_str())
+        }) {
+            return Err(span_error(
+                other.name.clone(),
+                &format!("`{}` can't be combined with `{name}`", other.name),
+            ));
+        }
     }
+    Ok(options)
 }
 
 /// Duplication of [`AllTuplesParsed`], but after it went through validation.
 struct AllTuples {
-    fake_variadic: bool,
-    macro_ident: Ident,
-    start: usize,
-    end: usize,
+    /// The cfg predicate for `#[doc(fake_variadic)]`, if it's given.
+    fake_variadic: Option<TokenStream2>,
+    /// The outer attributes other than `#[doc(fake_variadic)]`, which are forwarded by [`attrs`].
+    outer_attrs: TokenStream2,
+    callback: Callback,
+    /// The tokens of `; [..]`, which are passed to every invocation of a callback macro.
+    extra: TokenStream2,
+    options: Options,
+    /// Sorted and deduplicated.
+    arities: Vec<usize>,
+    /// The attributes of the arity tier that each arity belongs to, e.g.
+    /// `#[cfg(feature = "large-tuples")]` for `[0..=12, #[cfg(feature = "large-tuples")] 13..=32]`.
+    arity_attrs: Vec<TokenStream2>,
+    /// Where the arities were written, used for the size literals.
+    arities_span: Span,
     idents: Vec<Ident>,
+    /// What the base idents declare, in the same order as `idents`.
+    kinds: Vec<BaseKind>,
+    /// The bounds given after the base idents, or the type of a const generic.
+    bounds: Vec<Option<TokenStream2>>,
+}
+
+/// What a base ident declares, see [`BaseIdentParsed`].
+#[derive(Clone, Copy, PartialEq)]
+enum BaseKind {
+    /// `T`, which can also be used for bindings.
+    Type,
+    /// `'a`, whose generated idents are passed with a `'` in front of them.
+    Lifetime,
+    /// `const N: usize`
+    Const,
+}
+
+impl AllTuples {
+    fn max_arity(&self) -> usize {
+        self.arities.last().copied().unwrap_or_default()
+    }
+
+    /// Returns the span for a generated token that originates from `span`.
+    ///
+    /// Only the location is taken from `span`, the hygiene is still that of the call site
+    /// (or the mixed site), so that lints treat the token as generated.
+    fn span(&self, span: Span) -> Span {
+        let site = if self.options.mixed_site {
+            Span::mixed_site()
+        } else {
+            Span::call_site()
+        };
+        site.located_at(span)
+    }
+
+    /// Returns the length `n` of a tuple, e.g. `3usize`.
+    fn size_literal(&self, n: usize) -> Literal {
+        let mut literal = Literal::usize_suffixed(n);
+        literal.set_span(self.span(self.arities_span));
+        literal
+    }
+
+    /// Returns the index `i` of an element, e.g. `3`.
+    fn index_literal(&self, i: usize) -> Literal {
+        // Without base idents, the index is only passed for the empty elements.
+        let span = self.idents.first().map_or(self.arities_span, Ident::span);
+        let mut literal = Literal::usize_unsuffixed(i);
+        literal.set_span(self.span(span));
+        literal
+    }
+}
+
+/// What gets invoked for every arity.
+#[derive(Clone)]
+enum Callback {
+    /// The path to `some_macro` as written, which is invoked as `some_macro!(..)`.
+    Macro(TokenStream2),
+    /// The contents of `=> { .. }`, which are expanded by [`expand_template`].
+    Template(TokenStream2),
+    /// The item annotated with `#[variadic]`, which is expanded by [`expand_variadic_tokens`].
+    Item(TokenStream2),
 }
 
 /// Helper macro to generate tuple pyramids. Useful to generate scaffolding to work around Rust
-/// lacking variadics. Invoking `all_tuples!(impl_foo, start, end, P, Q, ..)`
-/// invokes `impl_foo` providing ident tuples through arity `start..end`.
+/// lacking variadics. Invoking `all_tuples!(impl_foo, start..=end, P, Q, ..)`
+/// invokes `impl_foo` providing ident tuples through arity `start..=end`.
 /// If you require the length of the tuple, see [`all_tuples_with_size!`].
 ///
+/// The callback doesn't need to be in textual scope, any path to a macro works, such as
+/// `crate::impls::impl_foo` or `$crate::__impl_foo` from within an exported macro.
+///
+/// The arities can be given as an inclusive range `start..=end`, an exclusive range
+/// `start..end`, or as `start, end`, which is the same as `start..=end`.
+/// Ranges can skip arities with `step`, e.g. `0..=16 step 2` for every even arity.
+/// Arities can also be listed explicitly, e.g. `[0, 1, 2, 4, 8..=16]`, where every entry
+/// is either a single arity or a range.
+///
+/// Every arity can be a constant integer expression such as `MAX - 1` or `8 * 2`, which makes
+/// it possible to forward them through `macro_rules!`:
+///
+/// ```
+/// # use variadics_please::all_tuples;
+/// #
+/// # macro_rules! impl_foo { ($($T:ident),*) => {}; }
+/// macro_rules! all_foo_tuples {
+///     ($max:expr) => {
+///         all_tuples!(impl_foo, 0..=$max - 1, T);
+///     };
+/// }
+///
+/// all_foo_tuples!(16);
+/// ```
+///
+/// Entries of the list can have attributes, which are passed to the callback for each of their
+/// arities like the outer attributes described below. This keeps large tuples behind a
+/// feature, so they don't slow down the build of everyone else:
+///
+/// ```
+/// # use variadics_please::all_tuples;
+/// #
+/// # macro_rules! impl_foo { ($(#[$meta:meta])* $($T:ident),*) => {}; }
+/// all_tuples!(
+///     impl_foo,
+///     [
+///         0..=12,
+///         #[cfg(feature = "large-tuples")] 13..=32,
+///         #[cfg(feature = "huge-tuples")] 33..=64,
+///     ],
+///     T
+/// );
+/// // impl_foo!(T0 .. T11);
+/// // impl_foo!(#[cfg(feature = "large-tuples")] T0 .. T12);
+/// // ..
+/// ```
+///
+/// With `#[doc(fake_variadic)]`, the generated documentation mentions the upper bound of every
+/// `#[cfg(..)]` tier, e.g. "With `feature = "large-tuples"`, it's also implemented for tuples up
+/// to 32 items long."
+///
 /// # Examples
 ///
 /// ## Single parameter
@@ -127,136 +673,502 @@ struct AllTuples {
 /// // impl_append!((P0, p0) .. (P19, p19));
 /// ```
 ///
-/// **`#[doc(fake_variadic)]`**
-///
-/// To improve the readability of your docs when implementing a trait for
-/// tuples or fn pointers of varying length you can use the rustdoc-internal `fake_variadic` marker.
-/// All your impls are collapsed and shown as a single `impl Trait for (F₁, F₂, …, Fₙ)`.
-///
-/// The `all_tuples!` macro does most of the work for you, the only change to your implementation macro
-/// is that you have to accept attributes using `$(#[$meta:meta])*`.
-///
-/// Since this feature requires a nightly compiler, it's only enabled on docs.rs by default.
-/// Add the following to your lib.rs if not already present:
+/// ## Bounds
 ///
-/// ```
-/// // `rustdoc_internals` is needed for `#[doc(fake_variadics)]`
-/// #![cfg_attr(any(docsrs, docsrs_dep), feature(rustdoc_internals))]
-/// ```
+/// Bounds can be attached to base idents, e.g. `T: Clone + Send + 'static`. The callback then
+/// receives a generics list and a where clause in brackets, which apply the bounds to every
+/// generated ident, before the elements. Base idents without bounds are left out of both, so
+/// they can still be used for bindings; `T:` includes one without any bounds.
 ///
 /// ```
 /// # use variadics_please::all_tuples;
 /// #
-/// trait Variadic {}
-///
-/// impl Variadic for () {}
+/// trait Describe {
+///     fn describe(&self) -> String;
+/// }
 ///
-/// macro_rules! impl_variadic {
-///     ($(#[$meta:meta])* $(($P:ident, $p:ident)),*) => {
-///         $(#[$meta])*
-///         impl<$($P),*> Variadic for ($($P,)*) {}
-///     }
+/// macro_rules! impl_describe {
+///     ([$($generics:tt)*], [$($where:tt)*], $(($T:ident, $t:ident)),*) => {
+///         impl<$($generics)*> Describe for ($($T,)*) $($where)* {
+///             fn describe(&self) -> String {
+///                 let ($($t,)*) = self;
+///                 [$(format!("{:?}", $t)),*].join(", ")
+///             }
+///         }
+///     };
 /// }
 ///
-/// all_tuples!(#[doc(fake_variadic)] impl_variadic, 1, 15, P, p);
+/// all_tuples!(impl_describe, 1, 15, T: core::fmt::Debug, t);
+/// // impl_describe!([T0: core::fmt::Debug], [where T0: core::fmt::Debug], (T0, t0));
+/// // impl_describe!(
+/// //     [T0: core::fmt::Debug, T1: core::fmt::Debug],
+/// //     [where T0: core::fmt::Debug, T1: core::fmt::Debug],
+/// //     (T0, t0),
+/// //     (T1, t1)
+/// // );
+/// // ..
+/// # assert_eq!((1, "a").describe(), "1, \"a\"");
 /// ```
-#[proc_macro]
-pub fn all_tuples(input: TokenStream) -> TokenStream {
-    let input = match parse_all_tuples(input) {
-        Ok(input) => input,
-        Err(err) => {
-            return err;
-        }
-    };
-    let ident_tuples = build_ident_tuples(&input);
-    let macro_ident = &input.macro_ident;
-    let invocations = make_invocation_range(&input).map(|n| {
-        let ident_tuples = choose_ident_tuples(&input, &ident_tuples, n);
-        let attrs = attrs(&input, n);
-        quote! { #macro_ident!(#attrs #ident_tuples); }
-    });
-    TokenStream::from(quote! { #(#invocations)* })
-}
-
-/// A variant of [`all_tuples!`] that enumerates its output.
 ///
-/// In particular, the tuples used by the inner macro will themselves be composed
-/// of tuples which contain the index.
+/// Either of them can be used, the other one is ignored by the callback. Bounds can refer to the
+/// other base idents, which are replaced with the idents of the same element, so
+/// `all_tuples!(impl_foo, 1, 15, 'a, T: 'a)` passes `T0: 'a0`.
 ///
-/// For example, with a single parameter:
-/// ```
-/// # use variadics_please::all_tuples_enumerated;
+/// ## Lifetimes and const generics
 ///
-/// trait Squawk {
-///     fn squawk(&self);
+/// Base idents can also be lifetimes like `'a`, or const generics like `const N: usize`. They
+/// are numbered like the other base idents and passed in the same positions, e.g. `('a0, N0)`,
+/// and are always part of the generics list, with the lifetimes in front.
+///
+/// ```
+/// # use variadics_please::all_tuples;
+/// #
+/// trait Lengths {
+///     fn lengths(&self) -> Vec<usize>;
 /// }
 ///
-/// // If every type in a tuple is `Squawk`, the tuple can squawk by having its
-/// // constituents squawk sequentially:
-/// macro_rules! impl_squawk {
-///     ($(($n:tt, $T:ident)),*) => {
-///         impl<$($T: Squawk),*> Squawk for ($($T,)*) {
-///             fn squawk(&self) {
-///                 $(
-///                     self.$n.squawk();
-///                 )*
+/// macro_rules! impl_lengths {
+///     ([$($generics:tt)*], [$($where:tt)*], $(($a:lifetime, $N:ident)),*) => {
+///         impl<$($generics)*> Lengths for ($(&$a [u8; $N],)*) {
+///             fn lengths(&self) -> Vec<usize> {
+///                 vec![$($N),*]
 ///             }
 ///         }
 ///     };
 /// }
 ///
-/// all_tuples_enumerated!(impl_squawk, 1, 15, T);
-/// // impl_squawk!((0, T0));
-/// // impl_squawk!((0, T0), (1, T1));
+/// all_tuples!(impl_lengths, 1, 15, 'a, const N: usize);
+/// // impl_lengths!(['a0, const N0: usize], [where], ('a0, N0));
+/// // impl_lengths!(['a0, 'a1, const N0: usize, const N1: usize], [where], ('a0, N0), ('a1, N1));
 /// // ..
-/// // impl_squawk!((0, T0) .. (14, T14));
+/// # assert_eq!((&[1, 2], &[3]).lengths(), vec![2, 1]);
+/// ```
+///
+/// Bounds, lifetimes and const generics aren't supported by inline templates, which can write
+/// `#T: Bound` themselves.
+///
+/// ## Extra tokens
+///
+/// Context that every invocation needs, such as a trait path or a lifetime, can be given in
+/// brackets or braces after a `;`. These tokens are passed verbatim in front of all other
+/// arguments, followed by a comma, so the same callback can be reused for different traits.
 ///
-/// all_tuples_enumerated!(impl_squawk, 16, 20, T);
-/// // impl_append!((0, T0) .. (15, T15));
-/// // ..
-/// // impl_append!((0, T0) .. (19, T19));
 /// ```
+/// # use variadics_please::all_tuples;
+/// #
+/// trait Foo {}
+/// trait Bar {}
 ///
-/// With multiple parameters, the result is similar, but with the additional parameters
-/// included in each tuple; e.g.:
-/// ```ignore
-/// all_tuples_enumerated!(impl_squawk, 1, 15, P, p);
-/// // impl_squawk!((0, P0, p0));
-/// // impl_squawk!((0, P0, p0), (1, P1, p1));
-/// // ..
-/// // impl_squawk!((0, P0, p0) .. (14, P14, p14));
+/// macro_rules! impl_marker {
+///     ($Trait:path, $($T:ident),*) => {
+///         impl<$($T: $Trait),*> $Trait for ($($T,)*) {}
+///     };
+/// }
 ///
-/// all_tuples_enumerated!(impl_append, 16, 20, P, p);
-/// // impl_append!((0, P0, p0) .. (15, P15, p15));
+/// all_tuples!(impl_marker, 1..=15, T; [Foo]);
+/// // impl_marker!(Foo, T0);
+/// // impl_marker!(Foo, T0, T1);
+/// // ..
+/// all_tuples!(impl_marker, 1..=15, T; [Bar]);
+/// // impl_marker!(Bar, T0);
 /// // ..
-/// // impl_append!((0, P0, p0) .. (19, P19, p19));
 /// ```
-#[proc_macro]
-pub fn all_tuples_enumerated(input: TokenStream) -> TokenStream {
-    let input = match parse_all_tuples(input) {
-        Ok(input) => input,
-        Err(err) => {
-            return err;
-        }
-    };
-    let ident_tuples = build_ident_tuples_enumerated(&input);
-    let macro_ident = &input.macro_ident;
-    let invocations = make_invocation_range(&input).map(|n| {
-        let ident_tuples = choose_ident_tuples_enumerated(&input, &ident_tuples, n);
-        let attrs = attrs(&input, n);
-        quote! { #macro_ident!(#attrs #ident_tuples); }
-    });
-    TokenStream::from(quote! { #(#invocations)* })
-}
-
-/// Helper macro to generate tuple pyramids with their length. Useful to generate scaffolding to
-/// work around Rust lacking variadics. Invoking `all_tuples_with_size!(impl_foo, start, end, P, Q, ..)`
-/// invokes `impl_foo` providing ident tuples through arity `start..end` preceded by their length.
-/// If you don't require the length of the tuple, see [`all_tuples!`].
 ///
-/// # Examples
+/// When combined with `#[doc(fake_variadic)]`, the extra tokens come before the attributes.
 ///
-/// ## Single parameter
+/// ## Naming
+///
+/// The generated idents are named `T0, T1, ..` by default. This can be changed with options,
+/// which are also given after a `;`:
+/// - `separator = "_"` puts a separator between the base ident and the number: `T_0, T_1, ..`
+/// - `one_based` starts counting at 1, which matches how rustdoc displays tuples: `T1, T2, ..`
+/// - `zero_pad` pads all numbers to the same width: `T00, T01, .., T15`
+/// - `alphabetic` uses letters instead of the base ident: `A, B, C, ..`, or `a, b, c, ..`
+///   if the base ident is lowercase
+/// - `repeat` doesn't number the idents at all, but repeats the base ident: `T, T, ..`.
+///   This is mostly useful with [`all_tuples_with_size!`] and [`all_tuples_enumerated!`],
+///   which still provide the length and indices
+///
+/// The first three can be combined with each other, but not with `alphabetic` or `repeat`.
+///
+/// Base idents have to be distinct, and must not generate the same idents. For example,
+/// `T, T1` is rejected because both generate `T11`, but it's fine with `separator = "_"`.
+/// Keywords and raw identifiers like `r#type` can't be used as base idents.
+///
+/// The generated idents keep the span of their base ident, and the lengths and indices passed
+/// by [`all_tuples_with_size!`] and [`all_tuples_enumerated!`] the span of the arities and the
+/// first base ident. Errors inside of the generated code therefore point at the invocation.
+/// With the `mixed_site` option, the generated idents use [`mixed_site`] hygiene instead,
+/// so they can't clash with the idents around the callback.
+///
+/// ```
+/// # use variadics_please::all_tuples;
+/// #
+/// # macro_rules! impl_foo { ($($T:ident),* $(,)?) => {}; }
+/// all_tuples!(impl_foo, 0..=15, T; [Foo]; separator = "_", one_based, zero_pad);
+/// // impl_foo!(Foo, );
+/// // impl_foo!(Foo, T_01);
+/// // ..
+/// // impl_foo!(Foo, T_01 .. T_15);
+/// all_tuples!(impl_foo, 0..=15, T; alphabetic);
+/// // impl_foo!();
+/// // impl_foo!(A);
+/// // ..
+/// // impl_foo!(A .. O);
+/// ```
+///
+/// [`mixed_site`]: proc_macro::Span::mixed_site
+///
+/// ## Fragments
+///
+/// With the `fragments` option, the callback additionally receives the tuple type, a pattern
+/// that destructures it into lowercase bindings, and the indices of its fields. They come in
+/// front of the attributes and elements, e.g. `impl_foo!((T0, T1,), (t0, t1,), [0, 1], T0, T1)`.
+/// The tuple type and the pattern always have a trailing comma, so 1-tuples are handled
+/// correctly. Only the first base ident is used for the fragments.
+///
+/// The indices are passed instead of access expressions like `self.0, self.1`, since
+/// `macro_rules!` hygiene doesn't let a `self` from outside of the callback refer to the
+/// receiver of a method inside of it. The callback writes `self.$i` instead.
+///
+/// ```
+/// # use variadics_please::all_tuples;
+/// #
+/// trait Sum {
+///     fn sum(&self) -> u32;
+///     fn sum_fields(&self) -> u32;
+/// }
+///
+/// macro_rules! impl_sum {
+///     ($Tuple:ty, ($($t:ident,)*), [$($i:tt),*], $($T:ident),*) => {
+///         impl<$($T: Copy + Into<u32>),*> Sum for $Tuple {
+///             fn sum(&self) -> u32 {
+///                 let ($($t,)*) = *self;
+///                 0 $(+ $t.into())*
+///             }
+///             fn sum_fields(&self) -> u32 {
+///                 0 $(+ self.$i.into())*
+///             }
+///         }
+///     };
+/// }
+///
+/// all_tuples!(impl_sum, 0..=15, T; fragments);
+/// # assert_eq!((1u8, 2u16).sum(), 3);
+/// # assert_eq!((1u8, 2u16).sum_fields(), 3);
+/// ```
+///
+/// ## Head and tail
+///
+/// Recursive impls, which implement a trait for a tuple based on the impl for a shorter tuple,
+/// need the first or the last element separately. With `split = head_tail`, the first element
+/// is separated from the rest with a `;`, e.g. `impl_foo!(T0; T1, T2)`. With `split = init_last`,
+/// the last element is separated instead, e.g. `impl_foo!(T0, T1; T2)`. Since there is no
+/// element to separate for the empty tuple, the arities have to start at 1.
+///
+/// ```
+/// # use variadics_please::all_tuples;
+/// #
+/// trait Len {
+///     const LEN: usize;
+/// }
+///
+/// impl Len for () {
+///     const LEN: usize = 0;
+/// }
+///
+/// macro_rules! impl_len {
+///     ($H:ident; $($T:ident),*) => {
+///         impl<$H, $($T),*> Len for ($H, $($T,)*) {
+///             const LEN: usize = 1 + <($($T,)*) as Len>::LEN;
+///         }
+///     };
+/// }
+///
+/// all_tuples!(impl_len, 1..=15, T; split = head_tail);
+/// // impl_len!(T0;);
+/// // impl_len!(T0; T1);
+/// // ..
+/// // impl_len!(T0; T1 .. T14);
+/// # assert_eq!(<((), (), ()) as Len>::LEN, 3);
+/// ```
+///
+/// Inductive impls that are based on the impl for the tuple without the last element can use
+/// the `previous` option instead. It passes the elements of the previous arity in brackets,
+/// right before the elements, e.g. `impl_foo!([T0, T1], T0, T1, T2)`. The arities have to
+/// start at 1 here as well.
+///
+/// ```
+/// # use variadics_please::all_tuples;
+/// #
+/// trait Len {
+///     const LEN: usize;
+/// }
+///
+/// impl Len for () {
+///     const LEN: usize = 0;
+/// }
+///
+/// macro_rules! impl_len {
+///     ([$($P:ident),*], $($T:ident),*) => {
+///         impl<$($T),*> Len for ($($T,)*)
+///         where
+///             ($($P,)*): Len,
+///         {
+///             const LEN: usize = 1 + <($($P,)*) as Len>::LEN;
+///         }
+///     };
+/// }
+///
+/// all_tuples!(impl_len, 1..=15, T; previous);
+/// // impl_len!([], T0);
+/// // impl_len!([T0], T0, T1);
+/// // ..
+/// // impl_len!([T0 .. T13], T0 .. T14);
+/// # assert_eq!(<((), (), ()) as Len>::LEN, 3);
+/// ```
+///
+/// ## Reversed order
+///
+/// The `reverse` option passes the elements from last to first, e.g. `impl_foo!(T2, T1, T0)`.
+/// With [`all_tuples_enumerated!`], every element keeps its index, so `self.$n` still refers to
+/// the same field. It is applied before `split` and `previous`, so `split = head_tail` then
+/// separates the last element.
+///
+/// ```
+/// # use variadics_please::all_tuples_enumerated;
+/// #
+/// trait Reverse {
+///     type Reversed;
+///     fn reverse(self) -> Self::Reversed;
+/// }
+///
+/// macro_rules! impl_reverse {
+///     ($Tuple:ty, $pat:pat, $indices:tt, $(($n:tt, $T:ident)),*) => {
+///         impl<$($T),*> Reverse for $Tuple {
+///             type Reversed = ($($T,)*);
+///             fn reverse(self) -> Self::Reversed {
+///                 ($(self.$n,)*)
+///             }
+///         }
+///     };
+/// }
+///
+/// // The fragments aren't reversed, so `$Tuple` is `(T0, T1, ..)`.
+/// all_tuples_enumerated!(impl_reverse, 0..=15, T; reverse, fragments);
+/// // impl_reverse!((), (), [],);
+/// // impl_reverse!((T0,), (t0,), [0], (0, T0));
+/// // impl_reverse!((T0, T1,), (t0, t1,), [0, 1], (1, T1), (0, T0));
+/// // ..
+/// # assert_eq!((1, 2, 3).reverse(), (3, 2, 1));
+/// ```
+///
+/// ## Inline templates
+///
+/// Instead of passing a callback macro, the code can also be written inline after a `=>`.
+/// The template uses `quote!`-style repetitions: `#(..)*` repeats its contents once per element,
+/// `#(..),*` does the same with a separator in between. Inside of a repetition, `#P` is replaced
+/// with the generated ident of the current element (`P0`, `P1`, ..) and `#i` with its index.
+/// `#N` is replaced with the arity and can be used anywhere.
+///
+/// ```
+/// # use variadics_please::all_tuples;
+/// #
+/// trait Append {
+///     type Out<Item>;
+///     fn append<Item>(tup: Self, item: Item) -> Self::Out<Item>;
+/// }
+///
+/// all_tuples!(0..=15, P, p => {
+///     impl<#(#P),*> Append for (#(#P,)*) {
+///         type Out<Item> = (#(#P,)* Item,);
+///         fn append<Item>((#(#p,)*): Self, item: Item) -> Self::Out<Item> {
+///             (#(#p,)* item,)
+///         }
+///     }
+/// });
+/// ```
+///
+/// The idents are the same ones a callback macro would receive. Any other `#` tokens, such as
+/// attributes, are left untouched. Forwarded outer attributes, the attributes of the arity tiers
+/// and the ones generated for `#[doc(fake_variadic)]` are placed in front of every item of the
+/// template, so with `#[doc(fake_variadic)]` each of its items should be an impl.
+///
+/// **`#[doc(fake_variadic)]`**
+///
+/// To improve the readability of your docs when implementing a trait for
+/// tuples or fn pointers of varying length you can use the rustdoc-internal `fake_variadic` marker.
+/// All your impls are collapsed and shown as a single `impl Trait for (F₁, F₂, …, Fₙ)`.
+///
+/// The `all_tuples!` macro does most of the work for you, the only change to your implementation macro
+/// is that you have to accept attributes using `$(#[$meta:meta])*`.
+///
+/// Since this feature requires a nightly compiler, it's only enabled on docs.rs by default.
+/// Add the following to your lib.rs if not already present:
+///
+/// ```
+/// // `rustdoc_internals` is needed for `#[doc(fake_variadics)]`
+/// #![cfg_attr(any(docsrs, docsrs_dep), feature(rustdoc_internals))]
+/// ```
+///
+/// ```
+/// # use variadics_please::all_tuples;
+/// #
+/// trait Variadic {}
+///
+/// impl Variadic for () {}
+///
+/// macro_rules! impl_variadic {
+///     ($(#[$meta:meta])* $(($P:ident, $p:ident)),*) => {
+///         $(#[$meta])*
+///         impl<$($P),*> Variadic for ($($P,)*) {}
+///     }
+/// }
+///
+/// all_tuples!(#[doc(fake_variadic)] impl_variadic, 1, 15, P, p);
+/// ```
+///
+/// If your docs are built with nightly under a different cfg, it can replace
+/// `any(docsrs, docsrs_dep)` with `#[doc(fake_variadic, cfg = my_docs)]`. Any cfg predicate
+/// works, e.g. `cfg = feature = "docs"` or `cfg = any(docsrs, my_docs)`, and
+/// `rustdoc_internals` then has to be enabled under the same predicate:
+///
+/// ```
+/// #![cfg_attr(my_docs, feature(rustdoc_internals))]
+/// # use variadics_please::all_tuples;
+/// #
+/// # trait Variadic {}
+/// #
+/// # macro_rules! impl_variadic {
+/// #     ($(#[$meta:meta])* $(($P:ident, $p:ident)),*) => {
+/// #         $(#[$meta])*
+/// #         impl<$($P),*> Variadic for ($($P,)*) {}
+/// #     }
+/// # }
+/// #
+/// all_tuples!(#[doc(fake_variadic, cfg = my_docs)] impl_variadic, 1, 15, P, p);
+/// ```
+///
+/// **Other attributes**
+///
+/// Any other outer attributes in front of the callback are forwarded to every invocation in the
+/// same way, in front of the ones generated for `#[doc(fake_variadic)]`. This avoids repeating
+/// lint levels or a `#[cfg(..)]` in every callback macro:
+///
+/// ```
+/// # use variadics_please::all_tuples;
+/// #
+/// trait Foo {}
+///
+/// macro_rules! impl_foo {
+///     ($(#[$meta:meta])* $($T:ident),*) => {
+///         $(#[$meta])*
+///         impl<$($T),*> Foo for ($($T,)*) {}
+///     }
+/// }
+///
+/// all_tuples!(#[allow(non_snake_case)] #[cfg(not(feature = "no_tuples"))] impl_foo, 0, 15, T);
+/// // impl_foo!(#[allow(non_snake_case)] #[cfg(not(feature = "no_tuples"))]);
+/// // impl_foo!(#[allow(non_snake_case)] #[cfg(not(feature = "no_tuples"))] T0);
+/// // ..
+/// ```
+#[proc_macro]
+pub fn all_tuples(input: TokenStream) -> TokenStream {
+    let input = match parse_all_tuples(input) {
+        Ok(input) => input,
+        Err(err) => {
+            return err;
+        }
+    };
+    let ident_tuples = build_ident_tuples(&input);
+    expand(&input, |n, attrs| {
+        let ident_tuples = choose_ident_tuples(&input, &ident_tuples, n);
+        quote! { #attrs #ident_tuples }
+    })
+}
+
+/// A variant of [`all_tuples!`] that enumerates its output.
+///
+/// In particular, the tuples used by the inner macro will themselves be composed
+/// of tuples which contain the index.
+///
+/// For example, with a single parameter:
+/// ```
+/// # use variadics_please::all_tuples_enumerated;
+///
+/// trait Squawk {
+///     fn squawk(&self);
+/// }
+///
+/// // If every type in a tuple is `Squawk`, the tuple can squawk by having its
+/// // constituents squawk sequentially:
+/// macro_rules! impl_squawk {
+///     ($(($n:tt, $T:ident)),*) => {
+///         impl<$($T: Squawk),*> Squawk for ($($T,)*) {
+///             fn squawk(&self) {
+///                 $(
+///                     self.$n.squawk();
+///                 )*
+///             }
+///         }
+///     };
+/// }
+///
+/// all_tuples_enumerated!(impl_squawk, 1, 15, T);
+/// // impl_squawk!((0, T0));
+/// // impl_squawk!((0, T0), (1, T1));
+/// // ..
+/// // impl_squawk!((0, T0) .. (14, T14));
+///
+/// all_tuples_enumerated!(impl_squawk, 16, 20, T);
+/// // impl_append!((0, T0) .. (15, T15));
+/// // ..
+/// // impl_append!((0, T0) .. (19, T19));
+/// ```
+///
+/// With multiple parameters, the result is similar, but with the additional parameters
+/// included in each tuple; e.g.:
+/// ```ignore
+/// all_tuples_enumerated!(impl_squawk, 1, 15, P, p);
+/// // impl_squawk!((0, P0, p0));
+/// // impl_squawk!((0, P0, p0), (1, P1, p1));
+/// // ..
+/// // impl_squawk!((0, P0, p0) .. (14, P14, p14));
+///
+/// all_tuples_enumerated!(impl_append, 16, 20, P, p);
+/// // impl_append!((0, P0, p0) .. (15, P15, p15));
+/// // ..
+/// // impl_append!((0, P0, p0) .. (19, P19, p19));
+/// ```
+///
+/// Extra tokens given as `; [..]` are passed in front of the tuples, like in [`all_tuples!`].
+///
+/// The inline template form described in [`all_tuples!`] is accepted as well, where `#i`
+/// provides the index of each element.
+#[proc_macro]
+pub fn all_tuples_enumerated(input: TokenStream) -> TokenStream {
+    let input = match parse_all_tuples(input) {
+        Ok(input) => input,
+        Err(err) => {
+            return err;
+        }
+    };
+    let ident_tuples = build_ident_tuples_enumerated(&input);
+    expand(&input, |n, attrs| {
+        let ident_tuples = choose_ident_tuples_enumerated(&input, &ident_tuples, n);
+        quote! { #attrs #ident_tuples }
+    })
+}
+
+/// Helper macro to generate tuple pyramids with their length. Useful to generate scaffolding to
+/// work around Rust lacking variadics. Invoking `all_tuples_with_size!(impl_foo, start..=end, P, Q, ..)`
+/// invokes `impl_foo` providing ident tuples through arity `start..=end` preceded by their length.
+/// The arities accept the same forms as in [`all_tuples!`].
+/// If you don't require the length of the tuple, see [`all_tuples!`].
+///
+/// # Examples
+///
+/// ## Single parameter
 ///
 /// ```
 /// # use core::marker::PhantomData;
@@ -353,71 +1265,1302 @@ pub fn all_tuples_enumerated(input: TokenStream) -> TokenStream {
 /// ```
 ///
 /// ```
-/// # use variadics_please::all_tuples_with_size;
-/// #
-/// trait Variadic {}
+/// # use variadics_please::all_tuples_with_size;
+/// #
+/// trait Variadic {}
+///
+/// impl Variadic for () {}
+///
+/// macro_rules! impl_variadic {
+///     ($N:expr, $(#[$meta:meta])* $(($P:ident, $p:ident)),*) => {
+///         $(#[$meta])*
+///         impl<$($P),*> Variadic for ($($P,)*) {}
+///     }
+/// }
+///
+/// all_tuples_with_size!(#[doc(fake_variadic)] impl_variadic, 1, 15, P, p);
+/// ```
+///
+/// Extra tokens given as `; [..]` are passed in front of the length, like in [`all_tuples!`],
+/// e.g. `all_tuples_with_size!(impl_foo, 0..=2, T; [Foo])` invokes `impl_foo!(Foo, 2, T0, T1)`.
+///
+/// ## Repeated idents
+///
+/// With the `repeat` option, every element uses the base idents themselves instead of numbered
+/// ones. Together with the length and indices, this covers impls for tuples of a single type:
+///
+/// ```
+/// # use variadics_please::all_tuples_enumerated_with_size;
+/// #
+/// trait IntoArray<T, const N: usize> {
+///     fn into_array(self) -> [T; N];
+/// }
+///
+/// macro_rules! impl_into_array {
+///     ($N:expr, $(($n:tt, $T:ident)),*) => {
+///         impl<T> IntoArray<T, $N> for ($($T,)*) {
+///             fn into_array(self) -> [T; $N] {
+///                 [$(self.$n),*]
+///             }
+///         }
+///     };
+/// }
+///
+/// all_tuples_enumerated_with_size!(impl_into_array, 1, 15, T; repeat);
+/// // impl_into_array!(1, (0, T));
+/// // impl_into_array!(2, (0, T), (1, T));
+/// // ..
+/// // impl_into_array!(15, (0, T) .. (14, T));
+/// # assert_eq!((1, 2, 3).into_array(), [1, 2, 3]);
+/// ```
+///
+/// ## Arity names
+///
+/// Families of items like `Zip2, Zip3, ..` need a name for every arity. The `names` option
+/// takes a list of names that contain `#N`, which are passed after the length with `#N`
+/// replaced by the arity. Since `Zip#N` isn't a valid token in Rust 2021, the names are given as
+/// strings. They keep the span of the string, also with the `mixed_site` option, so the
+/// generated items can be used outside of the callback.
+///
+/// ```
+/// # use variadics_please::all_tuples_with_size;
+/// #
+/// macro_rules! impl_zip {
+///     ($N:expr, $Zip:ident, $zip:ident, $(($I:ident, $i:ident)),*) => {
+///         pub struct $Zip<$($I),*>($($I,)*);
+///
+///         impl<$($I: Iterator),*> Iterator for $Zip<$($I),*> {
+///             type Item = ($($I::Item,)*);
+///             fn next(&mut self) -> Option<Self::Item> {
+///                 let $Zip($($i,)*) = self;
+///                 Some(($($i.next()?,)*))
+///             }
+///         }
+///
+///         pub fn $zip<$($I: IntoIterator),*>($($i: $I),*) -> $Zip<$($I::IntoIter),*> {
+///             $Zip($($i.into_iter(),)*)
+///         }
+///     };
+/// }
+///
+/// all_tuples_with_size!(impl_zip, 2, 8, I, i; names = ["Zip#N", "zip#N"]);
+/// // impl_zip!(2, Zip2, zip2, (I0, i0), (I1, i1));
+/// // impl_zip!(3, Zip3, zip3, (I0, i0), (I1, i1), (I2, i2));
+/// // ..
+/// // impl_zip!(8, Zip8, zip8, (I0, i0) .. (I7, i7));
+///
+/// let sums: Vec<u8> = zip3([1, 2], [3, 4], [5, 6]).map(|(a, b, c)| a + b + c).collect();
+/// assert_eq!(sums, [9, 12]);
+/// ```
+///
+/// The other macros accept `names` as well and pass them in front of the elements, except for
+/// [`all_tuple_pairs!`]. It can't be used with inline templates.
+///
+/// The inline template form described in [`all_tuples!`] is accepted as well, where `#N`
+/// provides the length of the tuple.
+#[proc_macro]
+pub fn all_tuples_with_size(input: TokenStream) -> TokenStream {
+    let input = match parse_all_tuples(input) {
+        Ok(input) => input,
+        Err(err) => {
+            return err;
+        }
+    };
+    let ident_tuples = build_ident_tuples(&input);
+    expand(&input, |n, attrs| {
+        let ident_tuples = choose_ident_tuples(&input, &ident_tuples, n);
+        let n = input.size_literal(n);
+        quote! { #n, #attrs #ident_tuples }
+    })
+}
+
+/// A combination of [`all_tuples_enumerated!`] and [`all_tuples_with_size!`], which passes the
+/// length of the tuple followed by the enumerated tuples.
+///
+/// ```
+/// # use variadics_please::all_tuples_enumerated_with_size;
+/// #
+/// trait Squawk {
+///     const LENGTH: usize;
+///     fn squawk(&self);
+/// }
+///
+/// macro_rules! impl_squawk {
+///     ($N:expr, $(($n:tt, $T:ident)),*) => {
+///         impl<$($T: Squawk),*> Squawk for ($($T,)*) {
+///             const LENGTH: usize = $N;
+///             fn squawk(&self) {
+///                 $(
+///                     self.$n.squawk();
+///                 )*
+///             }
+///         }
+///     };
+/// }
+///
+/// all_tuples_enumerated_with_size!(impl_squawk, 1, 15, T);
+/// // impl_squawk!(1, (0, T0));
+/// // impl_squawk!(2, (0, T0), (1, T1));
+/// // ..
+/// // impl_squawk!(15, (0, T0) .. (14, T14));
+/// ```
+///
+/// `#[doc(fake_variadic)]`, extra tokens and inline templates work the same as for
+/// [`all_tuples_with_size!`].
+#[proc_macro]
+pub fn all_tuples_enumerated_with_size(input: TokenStream) -> TokenStream {
+    let input = match parse_all_tuples(input) {
+        Ok(input) => input,
+        Err(err) => {
+            return err;
+        }
+    };
+    let ident_tuples = build_ident_tuples_enumerated(&input);
+    expand(&input, |n, attrs| {
+        let ident_tuples = choose_ident_tuples_enumerated(&input, &ident_tuples, n);
+        let n = input.size_literal(n);
+        quote! { #n, #attrs #ident_tuples }
+    })
+}
+
+/// A variant of [`all_tuples!`] that groups the elements into nested tuples of at most
+/// `chunk` elements each. Invoking `all_tuples_chunked!(impl_foo, chunk, start..=end, P, Q, ..)`
+/// invokes `impl_foo` with the chunks of every arity in `start..=end`.
+///
+/// This makes it possible to support many elements, e.g. a `((A, B, ..), (M, N, ..))` with 64
+/// elements in total, while every tuple is still small enough for the traits that std only
+/// implements for up to 12 elements, such as `Debug`, `PartialEq` or `Default`.
+///
+/// ```
+/// # use variadics_please::all_tuples_chunked;
+/// #
+/// trait Foo {}
+///
+/// macro_rules! impl_foo {
+///     ($(($($T:ident,)*)),*) => {
+///         impl<$($($T: Default + PartialEq),*),*> Foo for ($(($($T,)*),)*) {}
+///     };
+/// }
+///
+/// all_tuples_chunked!(impl_foo, 12, 0..=64, T);
+/// // impl_foo!();
+/// // impl_foo!((T0,));
+/// // ..
+/// // impl_foo!((T0, .., T11,), (T12,));
+/// // ..
+/// // impl_foo!((T0, .., T11,), .., (T60, .., T63,));
+/// ```
+///
+/// Every chunk is a tuple with a trailing comma, even if it only contains a single element.
+/// The arities are the total number of elements and accept the same forms as in
+/// [`all_tuples!`], as do the extra tokens and the naming options. Attributes like
+/// `#[doc(fake_variadic)]`, inline templates and the `fragments`, `split`, `previous` and
+/// `reverse` options aren't supported.
+#[proc_macro]
+pub fn all_tuples_chunked(input: TokenStream) -> TokenStream {
+    let (input, chunk) = match parse_all_tuples_chunked(input) {
+        Ok(input) => input,
+        Err(err) => {
+            return err;
+        }
+    };
+    let ident_tuples = build_ident_tuples(&input);
+    expand(&input, |n, attrs| {
+        let chunks: Vec<TokenStream2> = ident_tuples[..n]
+            .chunks(chunk)
+            .map(|chunk| quote! { (#(#chunk,)*) })
+            .collect();
+        quote! { #attrs #(#chunks),* }
+    })
+}
+
+fn parse_all_tuples_chunked(
+    input: TokenStream,
+) -> std::result::Result<(AllTuples, usize), TokenStream> {
+    let ts: TokenStream2 = input.into();
+    let mut iter = ts.to_token_iter();
+    let chunked = AllTuplesChunkedParsed::parse(&mut iter).map_err(pretty_print_error)?;
+    let chunk = match const_usize(&chunked.chunk, "chunk")? {
+        0 => {
+            return Err(span_error(chunked.chunk, "`chunk` should be at least 1"));
+        }
+        chunk => chunk,
+    };
+    let macro_path = chunked.macro_path.into_path();
+    let rest = chunked.rest;
+    let input = parse_all_tuples(quote! { #macro_path, #rest }.into())?;
+    let options = &input.options;
+    if options.fragments || options.split.is_some() || options.previous || options.reverse {
+        return Err(span_error(
+            rest,
+            "`all_tuples_chunked!` doesn't support the `fragments`, `split`, `previous` and `reverse` options",
+        ));
+    }
+    Ok((input, chunk))
+}
+
+/// Helper macro to generate impls for every combination of two tuples. Invoking
+/// `all_tuple_pairs!(impl_foo, 0..=4, A, ..; 0..=4, B, ..)` invokes `impl_foo` once for every
+/// pair of arities, passing the length and the elements of both tuples. The elements are
+/// wrapped in brackets and use different base idents, so they can't be confused.
+///
+/// ```
+/// # use variadics_please::all_tuple_pairs;
+/// #
+/// trait Convert<Out> {
+///     fn convert(self) -> Out;
+/// }
+///
+/// macro_rules! impl_convert {
+///     ($N:literal, [$($A:ident),*], $M:literal, [$($B:ident),*]) => {
+///         impl<$($A,)* $($B: Default),*> Convert<($($B,)*)> for ($($A,)*) {
+///             fn convert(self) -> ($($B,)*) {
+///                 ($($B::default(),)*)
+///             }
+///         }
+///     };
+/// }
+///
+/// all_tuple_pairs!(impl_convert, 0..=4, A; 0..=4, B);
+/// // impl_convert!(0, [], 0, []);
+/// // impl_convert!(0, [], 1, [B0]);
+/// // ..
+/// // impl_convert!(1, [A0], 0, []);
+/// // ..
+/// // impl_convert!(4, [A0, A1, A2, A3], 4, [B0, B1, B2, B3]);
+/// # let _: (u8, u16) = (1, 2, 3).convert();
+/// ```
+///
+/// Both tuples accept the same arities and multiple base idents like [`all_tuples!`]. The extra
+/// tokens and options are given after the second tuple and apply to both of them, with the
+/// [fragments](all_tuples!#fragments) of each tuple placed inside of its brackets.
+/// `#[doc(fake_variadic)]` and inline templates aren't supported.
+#[proc_macro]
+pub fn all_tuple_pairs(input: TokenStream) -> TokenStream {
+    let (first, second) = match parse_all_tuple_pairs(input) {
+        Ok(input) => input,
+        Err(err) => {
+            return err;
+        }
+    };
+    let Callback::Macro(macro_path) = &first.callback else {
+        unreachable!("`all_tuple_pairs!` always has a callback macro")
+    };
+    let extra = extra_args(&first);
+    let first_tuples = build_ident_tuples(&first);
+    let second_tuples = build_ident_tuples(&second);
+    let side = |input: &AllTuples, ident_tuples: &[TokenStream2], n: usize| {
+        let fragments = fragments(input, n);
+        let ident_tuples = choose_ident_tuples(input, ident_tuples, n);
+        let n = input.size_literal(n);
+        quote! { #n, [#fragments #ident_tuples] }
+    };
+    let mut invocations = Vec::new();
+    for &n in &first.arities {
+        for &m in &second.arities {
+            let first = side(&first, &first_tuples, n);
+            let second = side(&second, &second_tuples, m);
+            invocations.push(quote! { #macro_path!(#extra #first, #second); });
+        }
+    }
+    TokenStream::from(quote! { #(#invocations)* })
+}
+
+fn parse_all_tuple_pairs(
+    input: TokenStream,
+) -> std::result::Result<(AllTuples, AllTuples), TokenStream> {
+    let ts: TokenStream2 = input.into();
+    let mut iter = ts.to_token_iter();
+    let pairs = AllTuplePairsParsed::parse(&mut iter).map_err(pretty_print_error)?;
+    if let Some(token) = iter.next() {
+        return Err(span_error(token, "unexpected token"));
+    }
+    let callback = Callback::Macro(pairs.macro_path.into_path());
+    let (extra, options) = clauses(pairs.clauses, &callback)?;
+    if let Some(name) = options.names.first() {
+        return Err(span_error(
+            name.clone(),
+            "`all_tuple_pairs!` doesn't support the `names` option",
+        ));
+    }
+    let side = |parsed: TupleSideParsed| -> std::result::Result<AllTuples, TokenStream> {
+        let arities_span = first_span(&parsed.arities);
+        let (arities, arity_attrs) = arities(parsed.arities)?;
+        // The `#` doesn't keep its span, so the error points at the brackets.
+        if let Some(attr) = arity_attrs
+            .into_iter()
+            .find_map(|attrs| attrs.into_iter().nth(1))
+        {
+            return Err(span_error(
+                attr,
+                "`all_tuple_pairs!` doesn't support attributes on arities",
+            ));
+        }
+        check_needs_element(&options, &arities, arities_span)?;
+        let input = AllTuples {
+            fake_variadic: None,
+            outer_attrs: TokenStream2::new(),
+            callback: callback.clone(),
+            extra: extra.clone(),
+            options: options.clone(),
+            arity_attrs: vec![TokenStream2::new(); arities.len()],
+            arities,
+            arities_span,
+            idents: parsed.idents.iter().map(|i| i.value.clone()).collect(),
+            kinds: vec![BaseKind::Type; parsed.idents.len()],
+            bounds: vec![None; parsed.idents.len()],
+        };
+        validate_idents(&input)?;
+        Ok(input)
+    };
+    let first = side(pairs.first)?;
+    let second = side(pairs.second)?;
+
+    // The idents of both tuples end up in the same impl, so they have to be distinct.
+    let generated: std::collections::HashSet<String> = (0..first.max_arity())
+        .flat_map(|i| numbered_idents(&first, i))
+        .map(|ident| ident.to_string())
+        .collect();
+    for base in &second.idents {
+        if first.idents.contains(base) {
+            return Err(span_error(
+                base.clone(),
+                &format!("`{base}` is already used for the first tuple"),
+            ));
+        }
+        if let Some(ident) = (0..second.max_arity())
+            .map(|i| numbered_ident(&second, base, i))
+            .find(|ident| generated.contains(&ident.to_string()))
+        {
+            return Err(span_error(
+                base.clone(),
+                &format!("`{ident}` is generated for both tuples, rename one of them"),
+            ));
+        }
+    }
+    Ok((first, second))
+}
+
+/// The most invocations that [`all_subsets!`] and [`all_permutations!`] generate.
+const MAX_SET_INVOCATIONS: usize = 4096;
+
+/// Invokes a macro for every subset of a list of idents. Invoking
+/// `all_subsets!(impl_foo, [A, B, C])` invokes `impl_foo` with every combination of `A`, `B`
+/// and `C`, ordered by their length. The idents keep the order of the list.
+///
+/// ```
+/// # use variadics_please::all_subsets;
+/// #
+/// struct Position;
+/// struct Velocity;
+/// struct Health;
+///
+/// trait Bundle {}
+///
+/// macro_rules! impl_bundle {
+///     ($($T:ident),*) => {
+///         impl Bundle for ($($T,)*) {}
+///     };
+/// }
+///
+/// all_subsets!(impl_bundle, [Position, Velocity, Health]);
+/// // impl_bundle!();
+/// // impl_bundle!(Position);
+/// // impl_bundle!(Velocity);
+/// // impl_bundle!(Health);
+/// // impl_bundle!(Position, Velocity);
+/// // impl_bundle!(Position, Health);
+/// // impl_bundle!(Velocity, Health);
+/// // impl_bundle!(Position, Velocity, Health);
+/// ```
+///
+/// Since the number of subsets doubles with every ident, at most 4096 invocations are
+/// generated, which allows up to 12 idents.
+#[proc_macro]
+pub fn all_subsets(input: TokenStream) -> TokenStream {
+    let (macro_path, idents) = match parse_ident_set(input, "all_subsets", |len| {
+        u32::try_from(len)
+            .ok()
+            .and_then(|len| 1usize.checked_shl(len))
+    }) {
+        Ok(input) => input,
+        Err(err) => {
+            return err;
+        }
+    };
+    let mut subsets = Vec::new();
+    for len in 0..=idents.len() {
+        combinations(idents.len(), len, &mut Vec::new(), &mut subsets);
+    }
+    expand_ident_set(&macro_path, &idents, &subsets)
+}
+
+/// Invokes a macro for every ordering of a list of idents. Invoking
+/// `all_permutations!(impl_foo, [A, B, C])` invokes `impl_foo` with `A, B, C`, `A, C, B`,
+/// `B, A, C` and so on.
+///
+/// ```
+/// # use variadics_please::all_permutations;
+/// #
+/// struct Position;
+/// struct Velocity;
+/// struct Health;
+///
+/// trait Bundle {}
+///
+/// macro_rules! impl_bundle {
+///     ($($T:ident),*) => {
+///         impl Bundle for ($($T,)*) {}
+///     };
+/// }
+///
+/// all_permutations!(impl_bundle, [Position, Velocity, Health]);
+/// // impl_bundle!(Position, Velocity, Health);
+/// // impl_bundle!(Position, Health, Velocity);
+/// // impl_bundle!(Velocity, Position, Health);
+/// // ..
+/// // impl_bundle!(Health, Velocity, Position);
+/// ```
+///
+/// Since the number of permutations grows factorially, at most 4096 invocations are
+/// generated, which allows up to 6 idents.
+#[proc_macro]
+pub fn all_permutations(input: TokenStream) -> TokenStream {
+    let (macro_path, idents) = match parse_ident_set(input, "all_permutations", |len| {
+        (1..=len).try_fold(1usize, usize::checked_mul)
+    }) {
+        Ok(input) => input,
+        Err(err) => {
+            return err;
+        }
+    };
+    let mut permutations = Vec::new();
+    permute(
+        &mut (0..idents.len()).collect(),
+        &mut Vec::new(),
+        &mut permutations,
+    );
+    expand_ident_set(&macro_path, &idents, &permutations)
+}
+
+/// Parses the input of [`all_subsets!`] and [`all_permutations!`]. `count` returns how many
+/// invocations are generated for a list of the given length, or `None` on overflow.
+fn parse_ident_set(
+    input: TokenStream,
+    name: &str,
+    count: impl Fn(usize) -> Option<usize>,
+) -> std::result::Result<(TokenStream2, Vec<Ident>), TokenStream> {
+    let ts: TokenStream2 = input.into();
+    let mut iter = ts.to_token_iter();
+    let set = IdentSetParsed::parse(&mut iter).map_err(pretty_print_error)?;
+    if let Some(token) = iter.next() {
+        return Err(span_error(token, "unexpected token"));
+    }
+    let idents: Vec<Ident> = set.idents.content.iter().map(|i| i.value.clone()).collect();
+    for (position, ident) in idents.iter().enumerate() {
+        if idents[..position].contains(ident) {
+            return Err(span_error(
+                ident.clone(),
+                &format!("`{ident}` is listed more than once"),
+            ));
+        }
+    }
+    let allowed = |len| count(len).is_some_and(|count| count <= MAX_SET_INVOCATIONS);
+    if let Some(len) = (0..=idents.len()).find(|&len| !allowed(len)) {
+        let count = count(idents.len()).map_or("too many".to_string(), |count| count.to_string());
+        // Point at the first ident that exceeds the limit.
+        return Err(span_error(
+            idents[len - 1].clone(),
+            &format!(
+                "`{name}!` would generate {count} invocations for {} idents, but at most {MAX_SET_INVOCATIONS} are allowed",
+                idents.len()
+            ),
+        ));
+    }
+    Ok((set.macro_path.into_path(), idents))
+}
+
+/// Pushes every sorted combination of `len` indices below `n` to `output`, starting with
+/// the ones in `current`.
+fn combinations(n: usize, len: usize, current: &mut Vec<usize>, output: &mut Vec<Vec<usize>>) {
+    if current.len() == len {
+        output.push(current.clone());
+        return;
+    }
+    let start = current.last().map_or(0, |last| last + 1);
+    for i in start..n {
+        current.push(i);
+        combinations(n, len, current, output);
+        current.pop();
+    }
+}
+
+/// Pushes every ordering of `rest` to `output` in lexicographic order, starting with the
+/// indices in `current`.
+fn permute(rest: &mut Vec<usize>, current: &mut Vec<usize>, output: &mut Vec<Vec<usize>>) {
+    if rest.is_empty() {
+        output.push(current.clone());
+        return;
+    }
+    for pos in 0..rest.len() {
+        let i = rest.remove(pos);
+        current.push(i);
+        permute(rest, current, output);
+        current.pop();
+        rest.insert(pos, i);
+    }
+}
+
+/// Invokes `macro_path` once for every list of indices into `idents`.
+fn expand_ident_set(
+    macro_path: &TokenStream2,
+    idents: &[Ident],
+    sets: &[Vec<usize>],
+) -> TokenStream {
+    let invocations = sets.iter().map(|set| {
+        let idents = set.iter().map(|&i| &idents[i]);
+        quote! { #macro_path!(#(#idents),*); }
+    });
+    TokenStream::from(quote! { #(#invocations)* })
+}
+
+/// Implements a trait for tuples of every arity in `range`, based on a single impl block.
+///
+/// The impl is written once with a placeholder ident given by `over`, and the placeholder is
+/// replaced by the generated idents `T0, T1, ..` for every arity. This removes the need for a
+/// separate `macro_rules!` callback as required by [`all_tuples!`].
+///
+/// A comma separated list that mentions the placeholder is repeated once per element:
+/// - the generic parameters of the impl, e.g. `impl<T: Foo>` becomes `impl<T0: Foo, T1: Foo>`
+/// - `where` clauses, e.g. `where T: Foo` becomes `where T0: Foo, T1: Foo`
+/// - anything in parentheses, such as tuple types, tuple expressions, tuple patterns and
+///   function arguments, e.g. `(T::default(),)` becomes `(T0::default(), T1::default(),)`
+///
+/// Using the placeholder anywhere else results in an error. Within function bodies,
+/// `variadic_for!(pattern in tuple => expression)` evaluates `expression` once per element
+/// of `tuple`, with the element bound to `pattern` and the placeholder replaced by the ident
+/// of the current element. For the empty tuple, `expression` is dropped entirely, so a
+/// `let mut` that is only changed inside of it causes an `unused_mut` warning there.
+///
+/// `range` accepts the same forms as the arities of [`all_tuples!`], such as `start..=end`,
+/// `start..end`, `start..=end step n` or a list like `[0, 1, 2, 4]`.
+///
+/// # Examples
+///
+/// ```
+/// # use variadics_please::variadic;
+/// #
+/// trait Foo {
+///     fn foo(&self) -> usize;
+///     fn new() -> Self;
+/// }
+///
+/// impl Foo for u8 {
+///     fn foo(&self) -> usize {
+///         *self as usize
+///     }
+///     fn new() -> Self {
+///         1
+///     }
+/// }
+///
+/// #[variadic(range = 1..=15, over = T)]
+/// impl<T: Foo> Foo for (T,) {
+///     fn foo(&self) -> usize {
+///         let mut sum = 0;
+///         variadic_for!(t in self => sum += t.foo());
+///         sum
+///     }
+///     fn new() -> Self {
+///         (T::new(),)
+///     }
+/// }
+///
+/// assert_eq!((1u8, 2u8, 3u8).foo(), 6);
+/// assert_eq!(<(u8, u8)>::new(), (1, 1));
+/// ```
+///
+/// Multiple placeholders can be given as `over = (P, p)`, and every element then uses the
+/// idents with the same index, e.g. `P3` and `p3`. The naming options of [`all_tuples!`] can be
+/// added at the end, e.g. `#[variadic(range = 0..=15, over = T, one_based)]`.
+///
+/// **`#[doc(fake_variadic)]`**
+///
+/// `#[doc(fake_variadic)]` can be put on the impl, which works the same as for
+/// [`all_tuples!`], including a custom `cfg = ..` predicate. The impl for 1-tuples keeps the
+/// unnumbered placeholder.
+///
+/// ```
+/// // `rustdoc_internals` is needed for `#[doc(fake_variadics)]`
+/// #![cfg_attr(any(docsrs, docsrs_dep), feature(rustdoc_internals))]
+/// ```
+///
+/// ```
+/// # use variadics_please::variadic;
+/// #
+/// trait Variadic {}
+///
+/// #[variadic(range = 0..16, over = T)]
+/// #[doc(fake_variadic)]
+/// impl<T> Variadic for (T,) {}
+/// ```
+#[proc_macro_attribute]
+pub fn variadic(args: TokenStream, item: TokenStream) -> TokenStream {
+    let input = match parse_variadic(args, item) {
+        Ok(input) => input,
+        Err(err) => {
+            return err;
+        }
+    };
+    expand(&input, |_, _| {
+        unreachable!("`#[variadic]` never has a callback macro")
+    })
+}
+
+fn parse_variadic(
+    args: TokenStream,
+    item: TokenStream,
+) -> std::result::Result<AllTuples, TokenStream> {
+    let ts: TokenStream2 = args.into();
+    let mut iter = ts.to_token_iter();
+    let args = VariadicArgsParsed::parse(&mut iter).map_err(pretty_print_error)?;
+    if let Some(token) = iter.next() {
+        return Err(span_error(token, "unexpected token"));
+    }
+    let arities_span = first_span(&args.range);
+    let (arities, arity_attrs) = arities(args.range)?;
+    let idents = match args.over {
+        Either::First(ident) => vec![ident],
+        Either::Second(idents) => idents.content.iter().map(|i| i.value.clone()).collect(),
+        _ => unreachable!(),
+    };
+
+    // Strip `#[doc(fake_variadic)]` from the outer attributes, `attrs` generates it for us.
+    let item: Vec<TokenTree> = TokenStream2::from(item).into_iter().collect();
+    let mut fake_variadic = None;
+    let mut stripped = TokenStream2::new();
+    let mut pos = 0;
+    while let (Some(TokenTree::Punct(pound)), Some(TokenTree::Group(group))) =
+        (item.get(pos), item.get(pos + 1))
+    {
+        if pound.as_char() != '#' || group.delimiter() != Delimiter::Bracket {
+            break;
+        }
+        let attr: TokenStream2 = item[pos..pos + 2].iter().cloned().collect();
+        match fake_variadic_cfg(&attr)? {
+            Some(cfg) => fake_variadic = Some(cfg),
+            None => stripped.extend(attr),
+        }
+        pos += 2;
+    }
+    stripped.extend(item[pos..].iter().cloned());
+
+    let callback = Callback::Item(stripped);
+    let options = options(
+        args.options
+            .map(|options| {
+                options
+                    .second
+                    .into_iter()
+                    .map(|option| option.value)
+                    .collect()
+            })
+            .unwrap_or_default(),
+        &callback,
+    )?;
+    let input = AllTuples {
+        fake_variadic,
+        outer_attrs: TokenStream2::new(),
+        callback,
+        extra: TokenStream2::new(),
+        options,
+        arities,
+        arity_attrs,
+        arities_span,
+        kinds: vec![BaseKind::Type; idents.len()],
+        bounds: vec![None; idents.len()],
+        idents,
+    };
+    validate_idents(&input)?;
+    Ok(input)
+}
+
+/// Converts [`AritiesParsed`] into the sorted list of arities.
+fn arities(
+    parsed: AritiesParsed,
+) -> std::result::Result<(Vec<usize>, Vec<TokenStream2>), TokenStream> {
+    let items = match parsed {
+        AritiesParsed::Range(range) => vec![(TokenStream2::new(), ArityParsed::Range(range))],
+        AritiesParsed::List(list) => {
+            if list.content.is_empty() {
+                return Err(span_error(list, "expected at least one arity"));
+            }
+            list.content
+                .into_iter()
+                .map(|item| (item.value.attrs.to_token_stream(), item.value.arity))
+                .collect()
+        }
+        // `start, end` is the same as `start..=end`.
+        AritiesParsed::Positional { start, end, .. } => vec![(
+            TokenStream2::new(),
+            ArityParsed::Range(RangeParsed {
+                start,
+                limits: Either::First(DotDotEq::new()),
+                end,
+                step: None,
+            }),
+        )],
+        AritiesParsed::Invisible(group) => return arities(*group.content),
+    };
+    let mut arities = std::collections::BTreeMap::new();
+    for (attrs, item) in items {
+        let new: Vec<usize> = match &item {
+            ArityParsed::Range(range) => range_arities(range)?,
+            ArityParsed::Single(n) => vec![const_usize(n, "arities")?],
+        };
+        if let Some(n) = new
+            .into_iter()
+            .find(|&n| arities.insert(n, attrs.clone()).is_some())
+        {
+            return Err(span_error(
+                item,
+                &format!("arity {n} is listed more than once"),
+            ));
+        }
+    }
+    Ok(arities.into_iter().unzip())
+}
+
+/// Evaluates a constant expression like `MAX - 1`.
+fn const_usize(expr: &ConstExprParsed, name: &str) -> std::result::Result<usize, TokenStream> {
+    fn eval_expr(expr: &ConstExprParsed) -> Option<u128> {
+        expr.rest
+            .iter()
+            .try_fold(eval_term(&expr.first)?, |acc, op| {
+                let rhs = eval_term(&op.value.second)?;
+                match op.value.first {
+                    Either::First(_) => acc.checked_add(rhs),
+                    _ => acc.checked_sub(rhs),
+                }
+            })
+    }
+    fn eval_term(term: &ConstTermParsed) -> Option<u128> {
+        term.rest
+            .iter()
+            .try_fold(eval_operand(&term.first)?, |acc, op| {
+                let rhs = eval_operand(&op.value.second)?;
+                match op.value.first {
+                    Either::First(_) => acc.checked_mul(rhs),
+                    Either::Second(_) => acc.checked_div(rhs),
+                    _ => acc.checked_rem(rhs),
+                }
+            })
+    }
+    fn eval_operand(operand: &ConstOperandParsed) -> Option<u128> {
+        match operand {
+            ConstOperandParsed::Literal(literal) => Some(literal.value()),
+            ConstOperandParsed::Parenthesized(group) => eval_expr(&group.content),
+            ConstOperandParsed::Invisible(group) => eval_expr(&group.content),
+        }
+    }
+
+    eval_expr(expr)
+        .and_then(|value| value.try_into().ok())
+        .ok_or_else(|| {
+            span_error(
+                expr.clone(),
+                &format!("`{name}` should evaluate to an integer in the range of 0..usize::MAX"),
+            )
+        })
+}
+
+/// Returns the arities of `start..=end`, `start..end` or `start..=end step n`.
+fn range_arities(range: &RangeParsed) -> std::result::Result<Vec<usize>, TokenStream> {
+    let (start, end) = range_bounds(range)?;
+    let step = match &range.step {
+        Some(step) => match const_usize(&step.second, "step")? {
+            0 => {
+                return Err(span_error(
+                    step.second.clone(),
+                    "`step` should be at least 1",
+                ));
+            }
+            step => step,
+        },
+        None => 1,
+    };
+    Ok((start..=end).step_by(step).collect())
+}
+
+/// Converts `start..=end` or `start..end` into the inclusive bounds.
+fn range_bounds(range: &RangeParsed) -> std::result::Result<(usize, usize), TokenStream> {
+    let start = const_usize(&range.start, "start")?;
+    let end = const_usize(&range.end, "end")?;
+    let (inclusive_end, limits, expected) = match range.limits {
+        Either::First(_) => (Some(end), "..=", "<="),
+        Either::Second(_) => (end.checked_sub(1), "..", "<"),
+        _ => unreachable!(),
+    };
+    match inclusive_end {
+        Some(inclusive_end) if inclusive_end >= start => Ok((start, inclusive_end)),
+        _ => Err(span_error(
+            range.end.clone(),
+            &format!(
+                "`{start}{limits}{end}` is an empty range, `start` should be {expected} `end`"
+            ),
+        )),
+    }
+}
+
+fn parse_all_tuples(input: TokenStream) -> std::result::Result<AllTuples, TokenStream> {
+    let ts: TokenStream2 = input.into();
+    let mut iter = ts.to_token_iter();
+    let tuples = AllTuplesParsed::parse(&mut iter).map_err(pretty_print_error)?;
+    if let Some(token) = iter.next() {
+        return Err(span_error(token, "unexpected token"));
+    }
+    let callback = match (tuples.macro_path, tuples.template) {
+        (Some(macro_path), None) => Callback::Macro(macro_path.first.into_path()),
+        (None, Some(template)) => Callback::Template(template.second.0.stream()),
+        (Some(macro_path), Some(_)) => {
+            return Err(span_error(
+                macro_path.first,
+                "expected either a callback macro or a `=> { .. }` template, not both",
+            ));
+        }
+        (None, None) => {
+            return Err(span_error(
+                tuples.arities,
+                "expected a callback macro before the arities or a `=> { .. }` template at the end",
+            ));
+        }
+    };
+    let mut fake_variadic = None;
+    let mut outer_attrs = TokenStream2::new();
+    for attr in tuples.attrs {
+        match fake_variadic_cfg(&attr.value.to_token_stream())? {
+            None => outer_attrs.extend(attr.value.into_token_stream()),
+            Some(_) if fake_variadic.is_some() => {
+                return Err(span_error(
+                    attr.value.second,
+                    "`#[doc(fake_variadic)]` is given more than once",
+                ));
+            }
+            Some(cfg) => fake_variadic = Some(cfg),
+        }
+    }
+    let (extra, options) = clauses(tuples.clauses, &callback)?;
+    let arities_span = first_span(&tuples.arities);
+    let (arities, arity_attrs) = arities(tuples.arities)?;
+    check_needs_element(&options, &arities, arities_span)?;
+    let idents: Vec<Ident> = tuples
+        .idents
+        .iter()
+        .map(|i| i.value.ident.clone())
+        .collect();
+    let kinds: Vec<BaseKind> = tuples
+        .idents
+        .iter()
+        .map(|i| match i.value.kind {
+            None => BaseKind::Type,
+            Some(Either::First(_)) => BaseKind::Lifetime,
+            Some(Either::Second(_)) => BaseKind::Const,
+            _ => unreachable!(),
+        })
+        .collect();
+    let bounds: Vec<Option<TokenStream2>> = tuples
+        .idents
+        .iter()
+        .map(|i| i.value.bounds.as_ref().map(|b| b.second.to_token_stream()))
+        .collect();
+    for (position, ident) in idents.iter().enumerate() {
+        let message = match (kinds[position], &bounds[position]) {
+            (BaseKind::Const, None) => format!("expected `const {ident}: usize`"),
+            (BaseKind::Const, Some(ty)) if ty.is_empty() => {
+                format!("expected `const {ident}: usize`")
+            }
+            (BaseKind::Type, None) => continue,
+            _ if matches!(callback, Callback::Macro(_)) => continue,
+            (BaseKind::Type, Some(_)) => "bounds can only be given with a callback macro".into(),
+            _ => "lifetimes and const generics can only be given with a callback macro".into(),
+        };
+        return Err(span_error(ident.clone(), &message));
+    }
+    if matches!(callback, Callback::Template(_)) {
+        if let Some(ident) = idents.iter().find(|ident| *ident == "N" || *ident == "i") {
+            return Err(span_error(
+                ident.clone(),
+                "`N` and `i` are reserved for the arity and index inside of templates",
+            ));
+        }
+    }
+    let input = AllTuples {
+        fake_variadic,
+        outer_attrs,
+        callback,
+        extra,
+        options,
+        arities,
+        arity_attrs,
+        arities_span,
+        idents,
+        kinds,
+        bounds,
+    };
+    validate_idents(&input)?;
+    Ok(input)
+}
+
+/// Converts the clauses after the idents into the extra tokens and the [`Options`].
+fn clauses(
+    parsed: Any<Cons<Semicolon, ClauseParsed>>,
+    callback: &Callback,
+) -> std::result::Result<(TokenStream2, Options), TokenStream> {
+    let mut extra = None;
+    let mut parsed_options = Vec::new();
+    for clause in parsed {
+        match clause.value.second {
+            ClauseParsed::Extra(group) => {
+                if matches!(callback, Callback::Template(_)) {
+                    return Err(span_error(
+                        group,
+                        "extra tokens can only be passed to a callback macro",
+                    ));
+                }
+                if extra.is_some() {
+                    return Err(span_error(group, "extra tokens are given more than once"));
+                }
+                extra = Some(match group {
+                    Either::First(group) => group.0.stream(),
+                    Either::Second(group) => group.0.stream(),
+                    _ => unreachable!(),
+                });
+            }
+            ClauseParsed::Options(list) => {
+                parsed_options.extend(list.into_iter().map(|option| option.value));
+            }
+        }
+    }
+    let options = options(parsed_options, callback)?;
+    Ok((extra.unwrap_or_default(), options))
+}
+
+/// Checks that the arities start at 1 for the options that need at least one element.
+fn check_needs_element(
+    options: &Options,
+    arities: &[usize],
+    arities_span: Span,
+) -> std::result::Result<(), TokenStream> {
+    let needs_element = if options.split.is_some() {
+        Some("split")
+    } else if options.previous {
+        Some("previous")
+    } else {
+        None
+    };
+    match needs_element {
+        Some(option) if arities[0] == 0 => Err(error_at(
+            arities_span,
+            &format!("`{option}` needs at least one element, the arities should start at 1"),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Keywords, which can't be used as base idents since they are used unnumbered for
+/// `#[doc(fake_variadic)]`.
+const KEYWORDS: &[&str] = &[
+    "_", "Self", "abstract", "as", "async", "await", "become", "box", "break", "const", "continue",
+    "crate", "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if",
+    "impl", "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub",
+    "ref", "return", "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof",
+    "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/// Checks that the base idents are usable, and that no two elements get the same ident,
+/// e.g. `T` and `T1` both generate `T11`. Lifetimes only clash with other lifetimes.
+///
+/// `fragments` are built from the first base ident, so there has to be one:
+///
+/// ```compile_fail
+/// # use variadics_please::all_tuples;
+/// # macro_rules! impl_foo { ($($t:tt)*) => {}; }
+/// all_tuples!(impl_foo, 0..=2,; fragments);
+/// ```
+///
+/// `T10` is both `T` numbered `10` and `T1` numbered `0`:
+///
+/// ```compile_fail
+/// # use variadics_please::all_tuples;
+/// # macro_rules! impl_foo { ($($t:tt)*) => {}; }
+/// all_tuples!(impl_foo, 1, 12, T, T1);
+/// ```
+///
+/// A base ident can't be given twice:
+///
+/// ```compile_fail
+/// # use variadics_please::all_tuples;
+/// # macro_rules! impl_foo { ($($t:tt)*) => {}; }
+/// all_tuples!(impl_foo, 1, 2, T, T);
+/// ```
 ///
-/// impl Variadic for () {}
+/// Neither keywords nor raw identifiers can be base idents:
 ///
-/// macro_rules! impl_variadic {
-///     ($N:expr, $(#[$meta:meta])* $(($P:ident, $p:ident)),*) => {
-///         $(#[$meta])*
-///         impl<$($P),*> Variadic for ($($P,)*) {}
-///     }
-/// }
+/// ```compile_fail
+/// # use variadics_please::all_tuples;
+/// # macro_rules! impl_foo { ($($t:tt)*) => {}; }
+/// all_tuples!(impl_foo, 1, 2, type);
+/// ```
 ///
-/// all_tuples_with_size!(#[doc(fake_variadic)] impl_variadic, 1, 15, P, p);
+/// ```compile_fail
+/// # use variadics_please::all_tuples;
+/// # macro_rules! impl_foo { ($($t:tt)*) => {}; }
+/// all_tuples!(impl_foo, 1, 2, r#type);
 /// ```
-#[proc_macro]
-pub fn all_tuples_with_size(input: TokenStream) -> TokenStream {
-    let input = match parse_all_tuples(input) {
-        Ok(input) => input,
-        Err(err) => {
-            return err;
+fn validate_idents(input: &AllTuples) -> std::result::Result<(), TokenStream> {
+    if input.options.fragments {
+        // The fragments are built from the first base ident.
+        match (input.idents.first(), input.kinds.first()) {
+            (None, _) => {
+                return Err(error_at(
+                    input.arities_span,
+                    "`fragments` needs at least one base ident",
+                ));
+            }
+            (Some(ident), Some(kind)) if *kind != BaseKind::Type => {
+                return Err(span_error(
+                    ident.clone(),
+                    "`fragments` needs the first base ident to be a type",
+                ));
+            }
+            _ => {}
         }
+    }
+    let mut generated = std::collections::HashMap::new();
+    let same_namespace = |a: usize, b: usize| {
+        (input.kinds[a] == BaseKind::Lifetime) == (input.kinds[b] == BaseKind::Lifetime)
     };
-    let ident_tuples = build_ident_tuples(&input);
-    let macro_ident = &input.macro_ident;
-    let invocations = make_invocation_range(&input).map(|n| {
-        let ident_tuples = choose_ident_tuples(&input, &ident_tuples, n);
-        let attrs = attrs(&input, n);
-        quote! { #macro_ident!(#n, #attrs #ident_tuples); }
-    });
-    TokenStream::from(quote! { #(#invocations)* })
-}
-
-fn parse_all_tuples(input: TokenStream) -> std::result::Result<AllTuples, TokenStream> {
-    let ts: TokenStream2 = input.into();
-    let mut iter = ts.to_token_iter();
-    let tuples = AllTuplesParsed::parse(&mut iter).map_err(pretty_print_error)?;
-    let start: usize = match tuples.start.value().try_into() {
-        Ok(start) => start,
-        Err(_) => {
+    for (position, base) in input.idents.iter().enumerate() {
+        let name = base.to_string();
+        if name.starts_with("r#") {
             return Err(span_error(
-                tuples.start,
-                "`start` should be in the range of 0..usize::MAX",
+                base.clone(),
+                "raw identifiers can't be used as base idents",
             ));
         }
-    };
-    let end: usize = match tuples.end.value().try_into() {
-        Ok(end) => end,
-        Err(_) => {
+        if KEYWORDS.contains(&name.as_str()) {
             return Err(span_error(
-                tuples.end,
-                "`end` should be in the range of 0..usize::MAX",
+                base.clone(),
+                &format!("`{name}` is a keyword and can't be used as a base ident"),
             ));
         }
-    };
-    if end < start {
-        return Err(span_error(tuples.end, "`start` should <= `end`"));
-    }
-    Ok(AllTuples {
-        fake_variadic: tuples.fake_variadic.is_some(),
-        macro_ident: tuples.macro_ident,
-        start,
-        end,
-        idents: tuples.idents.iter().map(|i| i.value.clone()).collect(),
-    })
+        if (0..position)
+            .any(|other| input.idents[other] == *base && same_namespace(other, position))
+        {
+            return Err(span_error(
+                base.clone(),
+                &format!(
+                    "`{}` is given more than once",
+                    param(base.clone(), input.kinds[position])
+                ),
+            ));
+        }
+        if input.options.repeat {
+            // Every element uses the base idents, so they can't collide.
+            continue;
+        }
+        let lifetime = input.kinds[position] == BaseKind::Lifetime;
+        for i in 0..input.max_arity() {
+            let ident = numbered_ident(input, base, i).to_string();
+            if let Some(other) = generated.insert((lifetime, ident.clone()), base) {
+                return Err(span_error(
+                    base.clone(),
+                    &format!("`{other}` and `{base}` both generate `{ident}`, rename one of them"),
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Generates one invocation of the [`Callback`] per arity.
+///
+/// `args` receives the arity and the output of [`fragments`] and [`attrs`], and returns the
+/// arguments of the callback macro, which are preceded by the extra tokens of `; [..]`.
+/// Templates don't take any arguments, so `args` is unused for them.
+fn expand(input: &AllTuples, args: impl Fn(usize, TokenStream2) -> TokenStream2) -> TokenStream {
+    let extra = extra_args(input);
+    let invocations = make_invocation_range(input)
+        .map(|n| {
+            let attrs = attrs(input, n);
+            match &input.callback {
+                Callback::Macro(macro_path) => {
+                    let names = arity_names(input, n);
+                    let generics = generics(input, n);
+                    let fragments = fragments(input, n);
+                    let args = args(n, quote! { #names #generics #fragments #attrs });
+                    Ok(quote! { #macro_path!(#extra #args); })
+                }
+                Callback::Template(template) => {
+                    let idents = choose_idents(input, n);
+                    let body = expand_template(input, template, &idents, None)?;
+                    let items = template_items(body);
+                    Ok(quote! { #(#attrs #items)* })
+                }
+                Callback::Item(item) => {
+                    let idents = choose_idents(input, n);
+                    let tokens: Vec<TokenTree> = item.clone().into_iter().collect();
+                    let item = expand_variadic_tokens(input, &tokens, &idents)?;
+                    Ok(quote! { #attrs #item })
+                }
+            }
+        })
+        .collect::<std::result::Result<Vec<_>, TokenStream>>();
+    match invocations {
+        Ok(invocations) => TokenStream::from(quote! { #(#invocations)* }),
+        Err(err) => err,
+    }
+}
+
+/// Splits an expanded template into its items, so that the attributes can be put in front of
+/// each of them. An item ends with a `;` or with its `{ .. }` body, unless it is assigned a
+/// value like `const X: S = S { .. };`, which ends with the `;`.
+fn template_items(template: TokenStream2) -> Vec<TokenStream2> {
+    let mut items = Vec::new();
+    let mut item: Vec<TokenTree> = Vec::new();
+    for token in template {
+        let ends = match &token {
+            TokenTree::Punct(punct) => punct.as_char() == ';',
+            // `{ N }` inside of `<..>` is a const generic argument, not the body.
+            TokenTree::Group(group) => {
+                group.delimiter() == Delimiter::Brace && item_state(&item) == (0, false)
+            }
+            _ => false,
+        };
+        item.push(token);
+        if ends {
+            items.push(item.drain(..).collect());
+        }
+    }
+    if !item.is_empty() {
+        items.push(item.into_iter().collect());
+    }
+    items
+}
+
+/// Returns how many `<` of an item are still open, and whether it has an `=` outside of them,
+/// i.e. whether it is assigned a value.
+fn item_state(item: &[TokenTree]) -> (usize, bool) {
+    let mut depth = 0usize;
+    let mut assigned = false;
+    for pos in 0..item.len() {
+        match angle_bracket(item, pos) {
+            Some(true) => depth += 1,
+            Some(false) => depth = depth.saturating_sub(1),
+            None => {}
+        }
+        let joint = |pos: usize| matches!(item.get(pos), Some(TokenTree::Punct(punct)) if punct.spacing() == Spacing::Joint);
+        // Not `==`, `=>`, `<=`, `>=` or `!=`.
+        if depth == 0
+            && is_punct(item.get(pos), '=')
+            && !joint(pos)
+            && (pos == 0 || !joint(pos - 1))
+        {
+            assigned = true;
+        }
+    }
+    (depth, assigned)
+}
+
+/// Returns the extra tokens of `; [..]` followed by a comma, unless they already end with one.
+fn extra_args(input: &AllTuples) -> TokenStream2 {
+    match input.extra.clone().into_iter().last() {
+        None => TokenStream2::new(),
+        Some(last) if is_punct(Some(&last), ',') => input.extra.clone(),
+        Some(_) => {
+            let extra = &input.extra;
+            quote! { #extra, }
+        }
+    }
+}
+
+/// Expands the `#(..)*`, `#N`, `#i` and `#T` placeholders of a template for a single arity.
+///
+/// `idents` holds the generated idents of each element, `index` is the element of the
+/// repetition that is currently being expanded.
+fn expand_template(
+    input: &AllTuples,
+    template: &TokenStream2,
+    idents: &[Vec<Ident>],
+    index: Option<usize>,
+) -> std::result::Result<TokenStream2, TokenStream> {
+    let tokens: Vec<TokenTree> = template.clone().into_iter().collect();
+    let mut output = TokenStream2::new();
+    let mut pos = 0;
+    while pos < tokens.len() {
+        let token = &tokens[pos];
+        pos += 1;
+        match (token, tokens.get(pos)) {
+            (TokenTree::Punct(pound), Some(TokenTree::Group(group)))
+                if pound.as_char() == '#' && group.delimiter() == Delimiter::Parenthesis =>
+            {
+                pos += 1;
+                // Either `#(..)*` or `#(..) sep *`.
+                let separator = match (tokens.get(pos), tokens.get(pos + 1)) {
+                    (Some(TokenTree::Punct(star)), _) if star.as_char() == '*' => {
+                        pos += 1;
+                        None
+                    }
+                    (Some(separator), Some(TokenTree::Punct(star))) if star.as_char() == '*' => {
+                        pos += 2;
+                        Some(separator)
+                    }
+                    _ => return Err(span_error(group.clone(), "expected `*` after `#(..)`")),
+                };
+                for i in 0..idents.len() {
+                    if i > 0 {
+                        output.extend(separator.cloned());
+                    }
+                    output.extend(expand_template(input, &group.stream(), idents, Some(i))?);
+                }
+            }
+            (TokenTree::Punct(pound), Some(TokenTree::Ident(ident))) if pound.as_char() == '#' => {
+                let position = input.idents.iter().position(|base| base == ident);
+                if *ident == "N" {
+                    let mut n = Literal::usize_unsuffixed(idents.len());
+                    n.set_span(ident.span());
+                    output.extend([TokenTree::Literal(n)]);
+                } else if *ident == "i" || position.is_some() {
+                    let Some(index) = index else {
+                        return Err(span_error(
+                            ident.clone(),
+                            "this placeholder can only be used inside of `#(..)*`",
+                        ));
+                    };
+                    output.extend([match position {
+                        Some(position) => TokenTree::Ident(idents[index][position].clone()),
+                        None => {
+                            let mut i = Literal::usize_unsuffixed(index);
+                            i.set_span(ident.span());
+                            TokenTree::Literal(i)
+                        }
+                    }]);
+                } else {
+                    // Not one of our placeholders, leave both tokens alone.
+                    output.extend([token.clone(), TokenTree::Ident(ident.clone())]);
+                }
+                pos += 1;
+            }
+            (TokenTree::Group(group), _) => {
+                let stream = expand_template(input, &group.stream(), idents, index)?;
+                let mut expanded = Group::new(group.delimiter(), stream);
+                expanded.set_span(group.span());
+                output.extend([TokenTree::Group(expanded)]);
+            }
+            (token, _) => output.extend([token.clone()]),
+        }
+    }
+    Ok(output)
 }
 
 /// Unfortunately there's no upstream pretty-printing in `unsynn` yet:
@@ -437,47 +2580,488 @@ fn pretty_print_error(err: Error) -> TokenStream {
 }
 
 fn span_error(tokens: impl ToTokens, msg: &str) -> TokenStream {
-    let span = tokens
+    error_at(first_span(&tokens), msg)
+}
+
+fn error_at(span: Span, msg: &str) -> TokenStream {
+    quote::quote_spanned! { span => compile_error!(#msg); }.into()
+}
+
+/// Returns the span of the first token, or the call site if there are no tokens.
+fn first_span(tokens: &impl ToTokens) -> Span {
+    tokens
         .to_token_iter()
         .next()
         .map(|tt| tt.span())
-        .unwrap_or_else(Span::call_site);
-    quote::quote_spanned! { span => compile_error!(#msg); }.into()
+        .unwrap_or_else(Span::call_site)
 }
 
-fn build_ident_tuples(input: &AllTuples) -> Vec<TokenStream2> {
-    (0..input.end)
-        .map(|i| {
-            let idents = input
-                .idents
-                .iter()
-                .map(|ident| format_ident!("{}{}", ident, i));
-            to_ident_tuple(idents, input.idents.len())
+/// Expands the placeholders of an item annotated with `#[variadic]` for a single arity.
+///
+/// `idents` holds the generated idents of each element. Comma separated lists are handed
+/// to [`expand_variadic_list`], everything else is passed through unchanged.
+fn expand_variadic_tokens(
+    input: &AllTuples,
+    tokens: &[TokenTree],
+    idents: &[Vec<Ident>],
+) -> std::result::Result<TokenStream2, TokenStream> {
+    let mut output = TokenStream2::new();
+    let mut pos = 0;
+    while pos < tokens.len() {
+        let token = &tokens[pos];
+        pos += 1;
+        match token {
+            TokenTree::Ident(ident) if *ident == "impl" && is_punct(tokens.get(pos), '<') => {
+                let end = closing_angle_bracket(tokens, pos)?;
+                output.extend(tokens[pos - 1..=pos].iter().cloned());
+                output.extend(expand_variadic_list(input, &tokens[pos + 1..end], idents)?);
+                output.extend([tokens[end].clone()]);
+                pos = end + 1;
+            }
+            TokenTree::Ident(ident) if *ident == "where" => {
+                // The where clause ends with the body of the item, or with `;` if there is none.
+                let end = tokens[pos..]
+                    .iter()
+                    .position(|token| {
+                        is_punct(Some(token), ';')
+                            || matches!(token, TokenTree::Group(group) if group.delimiter() == Delimiter::Brace)
+                    })
+                    .map_or(tokens.len(), |end| pos + end);
+                output.extend([token.clone()]);
+                output.extend(expand_variadic_list(input, &tokens[pos..end], idents)?);
+                pos = end;
+            }
+            TokenTree::Ident(ident)
+                if *ident == "variadic_for" && is_punct(tokens.get(pos), '!') =>
+            {
+                let Some(TokenTree::Group(group)) = tokens.get(pos + 1) else {
+                    return Err(span_error(
+                        ident.clone(),
+                        "expected `variadic_for!(pattern in tuple => expression)`",
+                    ));
+                };
+                output.extend(expand_variadic_for(input, ident, group, idents)?);
+                pos += 2;
+            }
+            TokenTree::Ident(ident) if input.idents.contains(ident) => {
+                return Err(span_error(
+                    ident.clone(),
+                    "this placeholder can only be used in generic parameters, where clauses and parentheses",
+                ));
+            }
+            TokenTree::Group(group) => {
+                let inner: Vec<TokenTree> = group.stream().into_iter().collect();
+                let stream = if group.delimiter() == Delimiter::Parenthesis {
+                    expand_variadic_list(input, &inner, idents)?
+                } else {
+                    expand_variadic_tokens(input, &inner, idents)?
+                };
+                let mut expanded = Group::new(group.delimiter(), stream);
+                expanded.set_span(group.span());
+                output.extend([TokenTree::Group(expanded)]);
+            }
+            token => output.extend([token.clone()]),
+        }
+    }
+    Ok(output)
+}
+
+/// Expands a comma separated list, repeating each entry that mentions a placeholder once per
+/// element. Placeholders inside of nested parentheses are left to the nested list.
+fn expand_variadic_list(
+    input: &AllTuples,
+    tokens: &[TokenTree],
+    idents: &[Vec<Ident>],
+) -> std::result::Result<TokenStream2, TokenStream> {
+    let mut entries = Vec::new();
+    let mut depth = 0usize;
+    let mut entry_start = 0;
+    for (pos, token) in tokens.iter().enumerate() {
+        match (token, angle_bracket(tokens, pos)) {
+            (_, Some(true)) => depth += 1,
+            (_, Some(false)) => depth = depth.saturating_sub(1),
+            (TokenTree::Punct(punct), None) if punct.as_char() == ',' && depth == 0 => {
+                entries.push(&tokens[entry_start..pos]);
+                entry_start = pos + 1;
+            }
+            _ => {}
+        }
+    }
+    if entry_start < tokens.len() {
+        entries.push(&tokens[entry_start..]);
+    }
+
+    if !entries
+        .iter()
+        .any(|entry| mentions_placeholder(input, entry))
+    {
+        return expand_variadic_tokens(input, tokens, idents);
+    }
+    let mut expanded = Vec::new();
+    for entry in entries {
+        if mentions_placeholder(input, entry) {
+            expanded.extend(
+                idents
+                    .iter()
+                    .map(|element| replace_placeholders(input, entry, element)),
+            );
+        } else {
+            expanded.push(expand_variadic_tokens(input, entry, idents)?);
+        }
+    }
+    // Always use a trailing comma, so that 1-tuples stay tuples.
+    Ok(quote! { #(#expanded,)* })
+}
+
+/// Expands `variadic_for!(pattern in tuple => expression)` into a block that destructures
+/// `tuple` and evaluates `expression` for every element.
+fn expand_variadic_for(
+    input: &AllTuples,
+    macro_ident: &Ident,
+    group: &Group,
+    idents: &[Vec<Ident>],
+) -> std::result::Result<TokenStream2, TokenStream> {
+    let tokens: Vec<TokenTree> = group.stream().into_iter().collect();
+    let in_pos = tokens
+        .iter()
+        .position(|token| matches!(token, TokenTree::Ident(ident) if *ident == "in"));
+    let arrow_pos = in_pos.and_then(|in_pos| {
+        (in_pos + 1..tokens.len().saturating_sub(1)).find(|&pos| {
+            matches!(&tokens[pos], TokenTree::Punct(punct) if punct.as_char() == '=' && punct.spacing() == Spacing::Joint)
+                && is_punct(tokens.get(pos + 1), '>')
+        })
+    });
+    let (Some(in_pos), Some(arrow_pos)) = (in_pos, arrow_pos) else {
+        return Err(span_error(
+            macro_ident.clone(),
+            "expected `variadic_for!(pattern in tuple => expression)`",
+        ));
+    };
+    let pattern: TokenStream2 = tokens[..in_pos].iter().cloned().collect();
+    let tuple = expand_variadic_tokens(input, &tokens[in_pos + 1..arrow_pos], idents)?;
+    let body = &tokens[arrow_pos + 2..];
+
+    let bindings: Vec<Ident> = (0..idents.len())
+        .map(|i| format_ident!("__variadic_for_{}", i))
+        .collect();
+    let bodies = idents
+        .iter()
+        .map(|element| replace_placeholders(input, body, element));
+    Ok(quote! {
+        {
+            let (#(#bindings,)*) = #tuple;
+            #({
+                let #pattern = #bindings;
+                #bodies;
+            })*
+        }
+    })
+}
+
+/// Whether `tokens` contain a placeholder outside of nested parentheses.
+fn mentions_placeholder(input: &AllTuples, tokens: &[TokenTree]) -> bool {
+    tokens.iter().any(|token| match token {
+        TokenTree::Ident(ident) => input.idents.contains(ident),
+        TokenTree::Group(group) if group.delimiter() != Delimiter::Parenthesis => {
+            let inner: Vec<TokenTree> = group.stream().into_iter().collect();
+            mentions_placeholder(input, &inner)
+        }
+        _ => false,
+    })
+}
+
+/// Replaces every placeholder in `tokens` with the ident of the given element.
+fn replace_placeholders(
+    input: &AllTuples,
+    tokens: &[TokenTree],
+    element: &[Ident],
+) -> TokenStream2 {
+    tokens
+        .iter()
+        .map(|token| match token {
+            TokenTree::Ident(ident) => match input.idents.iter().position(|base| base == ident) {
+                Some(position) => TokenTree::Ident(element[position].clone()),
+                None => token.clone(),
+            },
+            TokenTree::Group(group) => {
+                let inner: Vec<TokenTree> = group.stream().into_iter().collect();
+                let mut replaced = Group::new(
+                    group.delimiter(),
+                    replace_placeholders(input, &inner, element),
+                );
+                replaced.set_span(group.span());
+                TokenTree::Group(replaced)
+            }
+            token => token.clone(),
         })
         .collect()
 }
 
-fn build_ident_tuples_enumerated(input: &AllTuples) -> Vec<TokenStream2> {
-    (0..input.end)
-        .map(|i| {
-            let idents = input
-                .idents
-                .iter()
-                .map(|ident| format_ident!("{}{}", ident, i));
-            to_ident_tuple_enumerated(idents, i)
+/// Returns the position of the `>` that closes the `<` at `open`.
+fn closing_angle_bracket(
+    tokens: &[TokenTree],
+    open: usize,
+) -> std::result::Result<usize, TokenStream> {
+    let mut depth = 0usize;
+    for pos in open..tokens.len() {
+        match angle_bracket(tokens, pos) {
+            Some(true) => depth += 1,
+            Some(false) => {
+                depth -= 1;
+                if depth == 0 {
+                    return Ok(pos);
+                }
+            }
+            None => {}
+        }
+    }
+    Err(span_error(tokens[open].clone(), "unclosed `<`"))
+}
+
+fn is_punct(token: Option<&TokenTree>, ch: char) -> bool {
+    matches!(token, Some(TokenTree::Punct(punct)) if punct.as_char() == ch)
+}
+
+/// Returns `Some(true)` if the token at `pos` opens an angle bracket, `Some(false)` if it closes
+/// one, and `None` otherwise. `->`, `=>`, `<=` and `>=` aren't angle brackets.
+fn angle_bracket(tokens: &[TokenTree], pos: usize) -> Option<bool> {
+    let joint = |pos: usize, chars: &[char]| matches!(tokens.get(pos), Some(TokenTree::Punct(punct)) if chars.contains(&punct.as_char()) && punct.spacing() == Spacing::Joint);
+    let TokenTree::Punct(punct) = &tokens[pos] else {
+        return None;
+    };
+    let comparison = punct.spacing() == Spacing::Joint && is_punct(tokens.get(pos + 1), '=');
+    let arrow = pos > 0 && joint(pos - 1, &['-', '=']);
+    match punct.as_char() {
+        '<' if !comparison => Some(true),
+        '>' if !comparison && !arrow => Some(false),
+        _ => None,
+    }
+}
+
+/// Returns the generated idents of the `i`-th element, e.g. `P3, p3` for `P, p`.
+fn numbered_idents(input: &AllTuples, i: usize) -> impl Iterator<Item = Ident> {
+    input
+        .idents
+        .iter()
+        .map(move |ident| numbered_ident(input, ident, i))
+}
+
+/// Like [`numbered_idents`], but with a `'` in front of lifetimes, e.g. `'a3, T3` for `'a, T`.
+fn numbered_params(input: &AllTuples, i: usize) -> impl Iterator<Item = TokenStream2> {
+    numbered_idents(input, i)
+        .zip(&input.kinds)
+        .map(|(ident, kind)| param(ident, *kind))
+}
+
+/// Returns the tokens of a generic parameter, which is `ident` with a `'` in front of it for
+/// lifetimes.
+fn param(ident: Ident, kind: BaseKind) -> TokenStream2 {
+    if kind == BaseKind::Lifetime {
+        let mut tick = Punct::new('\'', Spacing::Joint);
+        tick.set_span(ident.span());
+        quote! { #tick #ident }
+    } else {
+        ident.to_token_stream()
+    }
+}
+
+/// Returns the generated ident of the `i`-th element for `base`, named according to the
+/// [`Options`], e.g. `T3`, `T_3`, `T4`, `T03`, `D` or `T`.
+fn numbered_ident(input: &AllTuples, base: &Ident, i: usize) -> Ident {
+    let options = &input.options;
+    if options.repeat {
+        let mut ident = base.clone();
+        ident.set_span(input.span(base.span()));
+        return ident;
+    }
+    if options.alphabetic {
+        // A, B, .., Z, AA, AB, ..
+        let mut name = String::new();
+        let mut rest = i + 1;
+        while rest > 0 {
+            name.insert(0, (b'A' + ((rest - 1) % 26) as u8) as char);
+            rest = (rest - 1) / 26;
+        }
+        if base.to_string().starts_with(char::is_lowercase) {
+            name = name.to_lowercase();
+        }
+        return new_ident(input, &name, base.span());
+    }
+    let offset = usize::from(options.one_based);
+    let width = if options.zero_pad {
+        (input.max_arity().saturating_sub(1) + offset)
+            .to_string()
+            .len()
+    } else {
+        0
+    };
+    let name = format!("{}{}{:0width$}", base, options.separator, i + offset);
+    new_ident(input, &name, base.span())
+}
+
+/// Creates a generated ident that originates from `span`. Keywords, like the `as` that
+/// `alphabetic` reaches after 26 elements, are turned into raw identifiers.
+fn new_ident(input: &AllTuples, name: &str, span: Span) -> Ident {
+    let mut ident = if KEYWORDS.contains(&name) {
+        format_ident!("r#{}", name)
+    } else {
+        format_ident!("{}", name)
+    };
+    ident.set_span(input.span(span));
+    ident
+}
+
+/// Returns the names given with the `names` option for arity `n`, e.g. `Zip2, join2,` for
+/// `names = ["Zip#N", "join#N"]`. Since they name items that are used outside of the callback,
+/// they always resolve at the call site, even with the `mixed_site` option.
+fn arity_names(input: &AllTuples, n: usize) -> TokenStream2 {
+    let names = input.options.names.iter().map(|template| {
+        let mut name = format_ident!("{}", template.as_str().replace("#N", &n.to_string()));
+        name.set_span(Span::call_site().located_at(first_span(template)));
+        name
+    });
+    quote! { #(#names,)* }
+}
+
+/// Returns the generics list and where clause that are passed when base idents have bounds or
+/// are lifetimes or const generics, e.g. `['a0, T0: 'a0], [where T0: 'a0],` for `'a, T: 'a`.
+/// Type base idents without bounds are left out, since they don't have to be generic
+/// parameters. Lifetimes come first, as Rust requires them to.
+fn generics(input: &AllTuples, n: usize) -> TokenStream2 {
+    if input.bounds.iter().all(Option::is_none)
+        && input.kinds.iter().all(|kind| *kind == BaseKind::Type)
+    {
+        return TokenStream2::new();
+    }
+    let mut elements = choose_idents(input, n);
+    // With `repeat`, every element has the same idents, which are only declared once.
+    if input.options.repeat {
+        elements.truncate(1);
+    }
+    let mut lifetimes = Vec::new();
+    let mut params = Vec::new();
+    let mut predicates = Vec::new();
+    for element in &elements {
+        for (position, ident) in element.iter().enumerate() {
+            let kind = input.kinds[position];
+            let param = param(ident.clone(), kind);
+            let bounds = input.bounds[position]
+                .as_ref()
+                .map(|bounds| substitute_idents(input, bounds.clone(), element));
+            match (kind, bounds) {
+                (BaseKind::Const, Some(ty)) => params.push(quote! { const #param: #ty }),
+                (BaseKind::Lifetime, None) => lifetimes.push(param),
+                (BaseKind::Lifetime, Some(bounds)) => {
+                    lifetimes.push(quote! { #param: #bounds });
+                    predicates.push(quote! { #param: #bounds });
+                }
+                (_, Some(bounds)) => {
+                    params.push(quote! { #param: #bounds });
+                    predicates.push(quote! { #param: #bounds });
+                }
+                (_, None) => {}
+            }
+        }
+    }
+    lifetimes.extend(params);
+    quote! { [#(#lifetimes),*], [where #(#predicates),*], }
+}
+
+/// Replaces the base idents in `tokens` with the idents of `element`, so that bounds can
+/// refer to the other idents of the same element, e.g. `T: 'a` becomes `T0: 'a0`.
+fn substitute_idents(input: &AllTuples, tokens: TokenStream2, element: &[Ident]) -> TokenStream2 {
+    let mut lifetime = false;
+    tokens
+        .into_iter()
+        .map(|token| {
+            let after_tick = std::mem::replace(&mut lifetime, is_punct(Some(&token), '\''));
+            match token {
+                TokenTree::Ident(ident) => {
+                    let position =
+                        input
+                            .idents
+                            .iter()
+                            .zip(&input.kinds)
+                            .position(|(base, kind)| {
+                                *base == ident && (*kind == BaseKind::Lifetime) == after_tick
+                            });
+                    match position {
+                        Some(position) => TokenTree::Ident(element[position].clone()),
+                        None => TokenTree::Ident(ident),
+                    }
+                }
+                TokenTree::Group(group) => {
+                    let stream = substitute_idents(input, group.stream(), element);
+                    let mut substituted = Group::new(group.delimiter(), stream);
+                    substituted.set_span(group.span());
+                    TokenTree::Group(substituted)
+                }
+                token => token,
+            }
         })
         .collect()
 }
 
+/// Returns the fragments that are passed with the `fragments` option, e.g.
+/// `(T0, T1,), (t0, t1,), [0, 1],` for `T`. These are the tuple type, a pattern that
+/// destructures it, and the indices of its fields. Only the first base ident is used.
+///
+/// Unlike [`to_ident_tuple`], which leaves a single ident unwrapped, the tuple type and the
+/// pattern always get a trailing comma, so 1-tuples stay tuples.
+fn fragments(input: &AllTuples, n: usize) -> TokenStream2 {
+    if !input.options.fragments {
+        return TokenStream2::new();
+    }
+    let types: Vec<Ident> = choose_idents(input, n)
+        .into_iter()
+        .map(|element| element[0].clone())
+        .collect();
+    let bindings = types.iter().enumerate().map(|(i, ident)| {
+        let name = ident.to_string().to_lowercase();
+        if input.options.repeat {
+            // The bindings have to be distinct, even if the types are repeated.
+            new_ident(input, &format!("{name}{i}"), ident.span())
+        } else {
+            new_ident(input, &name, ident.span())
+        }
+    });
+    let indices = (0..types.len()).map(|i| input.index_literal(i));
+    quote! { (#(#types,)*), (#(#bindings,)*), [#(#indices),*], }
+}
+
+fn build_ident_tuples(input: &AllTuples) -> Vec<TokenStream2> {
+    (0..input.max_arity())
+        .map(|i| to_ident_tuple(numbered_params(input, i), input.idents.len()))
+        .collect()
+}
+
+fn build_ident_tuples_enumerated(input: &AllTuples) -> Vec<TokenStream2> {
+    (0..input.max_arity())
+        .map(|i| to_ident_tuple_enumerated(numbered_params(input, i), input.index_literal(i)))
+        .collect()
+}
+
 /// Returns an iterator over the invocation arities, including the optional fake-variadic `n=1`.
 fn make_invocation_range(input: &AllTuples) -> impl Iterator<Item = usize> {
-    let base = input.start..=input.end;
-    let extra: Vec<usize> = if input.fake_variadic && input.start > 1 {
-        vec![1]
+    let base = input.arities.clone();
+    let extra: Vec<usize> =
+        if input.fake_variadic.is_some() && !input.arities.contains(&1) && input.max_arity() > 1 {
+            vec![1]
+        } else {
+            vec![]
+        };
+    base.into_iter().chain(extra)
+}
+
+/// Like [`choose_ident_tuples`], but returns the idents of each element instead of tuples.
+fn choose_idents(input: &AllTuples, n: usize) -> Vec<Vec<Ident>> {
+    if input.fake_variadic.is_some() && n == 1 {
+        vec![input.idents.clone()]
     } else {
-        vec![]
-    };
-    base.chain(extra)
+        (0..n)
+            .map(|i| numbered_idents(input, i).collect())
+            .collect()
+    }
 }
 
 fn choose_ident_tuples(input: &AllTuples, ident_tuples: &[TokenStream2], n: usize) -> TokenStream2 {
@@ -485,12 +3069,14 @@ fn choose_ident_tuples(input: &AllTuples, ident_tuples: &[TokenStream2], n: usiz
     // idents with subscript numbers e.g. (F₁, F₂, …, Fₙ).
     // We don't want two numbers, so we use the
     // original, unnumbered idents for this case.
-    if input.fake_variadic && n == 1 {
-        let ident_tuple = to_ident_tuple(input.idents.iter().cloned(), input.idents.len());
-        quote! { #ident_tuple }
+    let previous = previous_ident_tuples(input, ident_tuples, n);
+    if input.fake_variadic.is_some() && n == 1 {
+        let ident_tuple = to_ident_tuple(base_params(input), input.idents.len());
+        let ident_tuples = join_ident_tuples(input, &[ident_tuple]);
+        quote! { #previous #ident_tuples }
     } else {
-        let ident_tuples = &ident_tuples[..n];
-        quote! { #(#ident_tuples),* }
+        let ident_tuples = join_ident_tuples(input, &ident_tuples[..n]);
+        quote! { #previous #ident_tuples }
     }
 }
 
@@ -499,16 +3085,65 @@ fn choose_ident_tuples_enumerated(
     ident_tuples: &[TokenStream2],
     n: usize,
 ) -> TokenStream2 {
-    if input.fake_variadic && n == 1 {
-        let ident_tuple = to_ident_tuple_enumerated(input.idents.iter().cloned(), 0);
-        quote! { #ident_tuple }
+    let previous = previous_ident_tuples(input, ident_tuples, n);
+    if input.fake_variadic.is_some() && n == 1 {
+        let ident_tuple = to_ident_tuple_enumerated(base_params(input), input.index_literal(0));
+        let ident_tuples = join_ident_tuples(input, &[ident_tuple]);
+        quote! { #previous #ident_tuples }
+    } else {
+        let ident_tuples = join_ident_tuples(input, &ident_tuples[..n]);
+        quote! { #previous #ident_tuples }
+    }
+}
+
+/// Returns the ident tuples of the previous arity `n - 1` in brackets for the `previous`
+/// option, e.g. `[T0, T1],` for `n = 3`.
+fn previous_ident_tuples(
+    input: &AllTuples,
+    ident_tuples: &[TokenStream2],
+    n: usize,
+) -> TokenStream2 {
+    if !input.options.previous {
+        return TokenStream2::new();
+    }
+    let ident_tuples = ordered(input, &ident_tuples[..n - 1]);
+    quote! { [#(#ident_tuples),*], }
+}
+
+/// Returns the ident tuples in the order in which they are passed, see the `reverse` option.
+fn ordered(input: &AllTuples, ident_tuples: &[TokenStream2]) -> Vec<TokenStream2> {
+    if input.options.reverse {
+        ident_tuples.iter().rev().cloned().collect()
     } else {
-        let ident_tuples = &ident_tuples[..n];
-        quote! { #(#ident_tuples),* }
+        ident_tuples.to_vec()
     }
 }
 
-fn to_ident_tuple(idents: impl Iterator<Item = Ident>, generic_num: usize) -> TokenStream2 {
+/// Joins the ident tuples of a single invocation with commas. With the `split` option, the
+/// first or the last one is separated from the others with a `;` instead.
+///
+/// The `reverse` option is applied first, the ident tuples keep their index though.
+fn join_ident_tuples(input: &AllTuples, ident_tuples: &[TokenStream2]) -> TokenStream2 {
+    let ident_tuples = &ordered(input, ident_tuples);
+    let at = match input.options.split {
+        None => return quote! { #(#ident_tuples),* },
+        Some(Split::HeadTail) => 1,
+        Some(Split::InitLast) => ident_tuples.len() - 1,
+    };
+    let (first, second) = ident_tuples.split_at(at);
+    quote! { #(#first),*; #(#second),* }
+}
+
+/// Returns the unnumbered base idents, with a `'` in front of lifetimes.
+fn base_params(input: &AllTuples) -> impl Iterator<Item = TokenStream2> {
+    input
+        .idents
+        .iter()
+        .zip(&input.kinds)
+        .map(|(ident, kind)| param(ident.clone(), *kind))
+}
+
+fn to_ident_tuple(idents: impl Iterator<Item = TokenStream2>, generic_num: usize) -> TokenStream2 {
     if generic_num < 2 {
         quote! { #(#idents)* }
     } else {
@@ -517,47 +3152,133 @@ fn to_ident_tuple(idents: impl Iterator<Item = Ident>, generic_num: usize) -> To
 }
 
 /// Like `to_ident_tuple`, but it enumerates the identifiers
-fn to_ident_tuple_enumerated(idents: impl Iterator<Item = Ident>, idx: usize) -> TokenStream2 {
-    let idx = Literal::usize_unsuffixed(idx);
+fn to_ident_tuple_enumerated(
+    idents: impl Iterator<Item = TokenStream2>,
+    idx: Literal,
+) -> TokenStream2 {
     quote! { (#idx, #(#idents),*) }
 }
 
+/// The doc comment for the impl that carries `#[doc(fake_variadic)]`.
+fn fake_variadic_doc(arities: &[usize]) -> String {
+    let (start, end) = (arities[0], arities[arities.len() - 1]);
+    if end - start + 1 != arities.len() {
+        // Not contiguous, so list them all, e.g. "0, 2, 4 or 8".
+        let (last, rest) = arities.split_last().unwrap();
+        let rest: Vec<String> = rest.iter().map(ToString::to_string).collect();
+        return format!(
+            "This trait is implemented for tuples {} or {last} items long.",
+            rest.join(", ")
+        );
+    }
+    format!(
+        "This trait is implemented for tuple{s1} {range} item{s2} long.",
+        range = if start == end {
+            format!("exactly {start}")
+        } else {
+            format!(
+                "{down}up to {end}",
+                down = if start != 0 {
+                    format!("down to {start} ")
+                } else {
+                    "".to_string()
+                },
+            )
+        },
+        s1 = if end > start { "s" } else { "" },
+        s2 = if end > 1 { "s" } else { "" }
+    )
+}
+
+/// The doc comment for the impl that carries `#[doc(fake_variadic)]`, which mentions the upper
+/// bounds of the arity tiers, e.g. "This trait is implemented for tuples up to 12 items long.
+/// With `feature = "large-tuples"`, it's also implemented for tuples up to 32 items long."
+///
+/// `conditions` holds the [`tier_condition`] of each of the `arities`.
+fn tiered_fake_variadic_doc(arities: &[usize], conditions: &[Option<String>]) -> String {
+    let unconditional: Vec<usize> = arities
+        .iter()
+        .zip(conditions)
+        .filter(|(_, condition)| condition.is_none())
+        .map(|(&n, _)| n)
+        .collect();
+    let mut sentences = Vec::new();
+    if !unconditional.is_empty() {
+        sentences.push(fake_variadic_doc(&unconditional));
+    }
+    // The condition of each tier and its largest arity, in the order they appear.
+    let mut tiers: Vec<(&str, usize)> = Vec::new();
+    for (&n, condition) in arities.iter().zip(conditions) {
+        let Some(condition) = condition else {
+            continue;
+        };
+        match tiers.iter_mut().find(|(other, _)| other == condition) {
+            Some(tier) => tier.1 = n,
+            None => tiers.push((condition, n)),
+        }
+    }
+    for (condition, end) in tiers {
+        sentences.push(if sentences.is_empty() {
+            format!(
+                "With `{condition}`, this trait is implemented for tuples up to {end} items long."
+            )
+        } else {
+            format!("With `{condition}`, it's also implemented for tuples up to {end} items long.")
+        });
+    }
+    sentences.join(" ")
+}
+
+/// Returns the predicates of the `#[cfg(..)]` attributes of an arity tier, e.g.
+/// `feature = "large-tuples"`, or `None` if the tier is always available.
+fn tier_condition(attrs: &TokenStream2) -> Option<String> {
+    let predicates: Vec<String> = attrs
+        .clone()
+        .into_iter()
+        .filter_map(|token| {
+            let TokenTree::Group(attr) = token else {
+                return None;
+            };
+            let attr: Vec<TokenTree> = attr.stream().into_iter().collect();
+            match attr.as_slice() {
+                [TokenTree::Ident(cfg), TokenTree::Group(predicate)] if *cfg == "cfg" => {
+                    Some(predicate.stream().to_string())
+                }
+                _ => None,
+            }
+        })
+        .collect();
+    (!predicates.is_empty()).then(|| predicates.join("` and `"))
+}
+
 /// n: number of elements
 fn attrs(input: &AllTuples, n: usize) -> TokenStream2 {
-    if !input.fake_variadic {
+    let outer_attrs = &input.outer_attrs;
+    let arity_attrs = match input.arities.binary_search(&n) {
+        Ok(position) => input.arity_attrs[position].clone(),
+        // The extra impl for `#[doc(fake_variadic)]`, which only exists for the docs.
+        Err(_) => TokenStream2::new(),
+    };
+    let fake_variadic_attrs = fake_variadic_attrs(input, n);
+    quote! { #outer_attrs #arity_attrs #fake_variadic_attrs }
+}
+
+/// The attributes that implement `#[doc(fake_variadic)]` for `n` elements.
+fn fake_variadic_attrs(input: &AllTuples, n: usize) -> TokenStream2 {
+    let Some(cfg) = &input.fake_variadic else {
         return TokenStream2::default();
-    }
+    };
     match n {
         // An empty tuple (i.e. the unit type) is still documented separately,
         // so no `#[doc(hidden)]` here.
         0 => TokenStream2::default(),
         n => {
-            let cfg = quote! { any(docsrs, docsrs_dep) };
             // The `#[doc(fake_variadic)]` attr has to be on the first impl block.
             if n == 1 {
-                let doc = Literal::string(&format!(
-                    "This trait is implemented for tuple{s1} {range} item{s2} long.",
-                    range = if input.start == input.end {
-                        format!("exactly {}", input.start)
-                    } else {
-                        format!(
-                            "{down}up to {up}",
-                            down = if input.start != 0 {
-                                format!("down to {} ", input.start)
-                            } else {
-                                "".to_string()
-                            },
-                            up = input.end
-                        )
-                    },
-                    s1 = if input.end > input.start { "s" } else { "" },
-                    s2 = if input.end >= input.start && input.end > 1 {
-                        "s"
-                    } else {
-                        ""
-                    }
-                ));
-                if input.start <= 1 && input.end >= 1 {
+                let conditions: Vec<Option<String>> =
+                    input.arity_attrs.iter().map(tier_condition).collect();
+                let doc = Literal::string(&tiered_fake_variadic_doc(&input.arities, &conditions));
+                if input.arities.contains(&1) {
                     // n == 1 and it's included
                     quote! {
                         #[cfg_attr(#cfg, doc(fake_variadic))]
@@ -578,3 +3299,59 @@ fn attrs(input: &AllTuples, n: usize) -> TokenStream2 {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{fake_variadic_doc, tiered_fake_variadic_doc};
+
+    #[test]
+    fn fake_variadic_doc_contiguous() {
+        assert_eq!(
+            fake_variadic_doc(&[0, 1, 2, 3]),
+            "This trait is implemented for tuples up to 3 items long."
+        );
+        assert_eq!(
+            fake_variadic_doc(&[2, 3, 4]),
+            "This trait is implemented for tuples down to 2 up to 4 items long."
+        );
+        assert_eq!(
+            fake_variadic_doc(&[1]),
+            "This trait is implemented for tuple exactly 1 item long."
+        );
+        assert_eq!(
+            fake_variadic_doc(&[3]),
+            "This trait is implemented for tuple exactly 3 items long."
+        );
+    }
+
+    #[test]
+    fn fake_variadic_doc_non_contiguous() {
+        assert_eq!(
+            fake_variadic_doc(&[0, 2, 4, 8]),
+            "This trait is implemented for tuples 0, 2, 4 or 8 items long."
+        );
+    }
+
+    #[test]
+    fn tiered_fake_variadic_doc_with_unconditional_tier() {
+        let large = Some("feature = \"large-tuples\"".to_string());
+        let conditions = [None, None, None, large.clone(), large];
+        assert_eq!(
+            tiered_fake_variadic_doc(&[0, 1, 2, 3, 4], &conditions),
+            "This trait is implemented for tuples up to 2 items long. \
+             With `feature = \"large-tuples\"`, it's also implemented for tuples up to 4 items long."
+        );
+    }
+
+    #[test]
+    fn tiered_fake_variadic_doc_all_conditional() {
+        let small = Some("feature = \"small\"".to_string());
+        let large = Some("feature = \"large\"".to_string());
+        let conditions = [small.clone(), small, large.clone(), large];
+        assert_eq!(
+            tiered_fake_variadic_doc(&[1, 2, 3, 4], &conditions),
+            "With `feature = \"small\"`, this trait is implemented for tuples up to 2 items long. \
+             With `feature = \"large\"`, it's also implemented for tuples up to 4 items long."
+        );
+    }
+}
@@ -0,0 +1,107 @@
+#![allow(missing_docs, dead_code)]
+#![cfg_attr(docsrs, feature(rustdoc_internals))]
+
+use static_assertions::{assert_impl_one, assert_not_impl_any};
+use variadics_please::{all_tuples, all_tuples_enumerated, all_tuples_with_size};
+
+trait Foo {}
+
+macro_rules! foo {
+    ($(#[$meta: meta])* $($t: ident),* $(,)?) => {
+        $(#[$meta])*
+        impl<$($t),*> Foo for ($($t,)*) {}
+    };
+}
+
+all_tuples!(
+    #[doc(fake_variadic)]
+    foo,
+    [
+        0..=1,
+        #[cfg(all())]
+        2,
+        #[cfg(any())]
+        3..=4
+    ],
+    T
+);
+
+trait Bar {}
+
+macro_rules! bar {
+    ($(#[$meta: meta])* $(($n: tt, $t: ident)),* $(,)?) => {
+        $(#[$meta])*
+        impl<$($t),*> Bar for ($($t,)*) {}
+    };
+}
+
+all_tuples_enumerated!(
+    bar,
+    [
+        1,
+        #[cfg(any())]
+        #[allow(unused)]
+        2,
+        3
+    ],
+    T
+);
+
+trait Baz {
+    const SIZE: usize;
+}
+
+macro_rules! baz {
+    ($size: literal, $(#[$meta: meta])* $($t: ident),* $(,)?) => {
+        $(#[$meta])*
+        impl<$($t),*> Baz for ($($t,)*) {
+            const SIZE: usize = $size;
+        }
+    };
+}
+
+all_tuples_with_size!(
+    baz,
+    [
+        #[cfg(all())]
+        0..2,
+        #[cfg(any())]
+        2..4
+    ],
+    T
+);
+
+trait First {}
+
+trait Second {}
+
+// the tier applies to every item of the template
+all_tuples!([0..=1, #[cfg(any())] 2..=3], T => {
+    impl<#(#T),*> First for (#(#T,)*) {}
+
+    impl<#(#T),*> Second for (#(#T,)*)
+    where
+        #(#T: Clone,)*
+    {
+    }
+});
+
+#[test]
+fn arity_attrs_test() {
+    assert_impl_one!((): Foo);
+    assert_impl_one!(((), ()): Foo);
+    assert_not_impl_any!(((), (), ()): Foo);
+
+    assert_impl_one!(((),): Bar);
+    assert_not_impl_any!(((), ()): Bar);
+    assert_impl_one!(((), (), ()): Bar);
+
+    assert_eq!(<((),) as Baz>::SIZE, 1);
+    assert_not_impl_any!(((), ()): Baz);
+
+    assert_impl_one!(((),): First);
+    assert_impl_one!(((),): Second);
+    assert_not_impl_any!(((), ()): First);
+    assert_not_impl_any!(((), ()): Second);
+    assert_not_impl_any!(((), (), ()): Second);
+}
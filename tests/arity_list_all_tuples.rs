@@ -0,0 +1,95 @@
+#![allow(missing_docs, dead_code)]
+#![cfg_attr(docsrs, feature(rustdoc_internals))]
+
+use static_assertions::{assert_impl_one, assert_not_impl_any};
+use variadics_please::{all_tuples, all_tuples_enumerated, all_tuples_with_size};
+
+trait Foo {}
+
+macro_rules! foo {
+    ($(#[$meta: meta])* $($t: ident),* $(,)?) => {
+        $(#[$meta])*
+        impl<$($t),*> Foo for ($($t,)*) {}
+    };
+}
+
+// {0, 2, 4, 5, 6}
+all_tuples!(foo, [0, 2, 4..=6], T);
+
+trait Bar {
+    const SIZE: usize;
+}
+
+macro_rules! bar {
+    ($size: literal, $($t: ident),* $(,)?) => {
+        impl<$($t),*> Bar for ($($t,)*) {
+            const SIZE: usize = $size;
+        }
+    };
+}
+
+// {1, 3, 5}
+all_tuples_with_size!(bar, 1..=6 step 2, T);
+
+trait Baz {}
+
+macro_rules! baz {
+    ($(($_: literal, $t: ident)),* $(,)?) => {
+        impl<$($t),*> Baz for ($($t,)*) {}
+    };
+}
+
+// {0, 4, 8}
+all_tuples_enumerated!(baz, [0..9 step 4], T);
+
+trait Qux {}
+
+macro_rules! qux {
+    ($(#[$meta: meta])* $($t: ident),* $(,)?) => {
+        $(#[$meta])*
+        impl<$($t),*> Qux for ($($t,)*) {}
+    };
+}
+
+// no {1}
+all_tuples!(
+    #[doc(fake_variadic)]
+    qux,
+    [2, 4],
+    T
+);
+
+#[test]
+fn arity_list_test() {
+    assert_impl_one!((): Foo);
+    assert_not_impl_any!(((),): Foo);
+    assert_impl_one!(((), ()): Foo);
+    assert_not_impl_any!(((), (), ()): Foo);
+    assert_impl_one!(((), (), (), ()): Foo);
+    assert_impl_one!(((), (), (), (), ()): Foo);
+    assert_impl_one!(((), (), (), (), (), ()): Foo);
+    assert_not_impl_any!(((), (), (), (), (), (), ()): Foo);
+
+    assert_not_impl_any!((): Bar);
+    assert_eq!(<((),) as Bar>::SIZE, 1);
+    assert_not_impl_any!(((), ()): Bar);
+    assert_eq!(<((), (), ()) as Bar>::SIZE, 3);
+    assert_not_impl_any!(((), (), (), ()): Bar);
+    assert_eq!(<((), (), (), (), ()) as Bar>::SIZE, 5);
+    assert_not_impl_any!(((), (), (), (), (), ()): Bar);
+
+    assert_impl_one!((): Baz);
+    assert_not_impl_any!(((),): Baz);
+    assert_impl_one!(((), (), (), ()): Baz);
+    assert_impl_one!(((), (), (), (), (), (), (), ()): Baz);
+    assert_not_impl_any!(((), (), (), (), (), (), (), (), ()): Baz);
+
+    // only impl for (T,) with `docsrs`
+    #[cfg(docsrs)]
+    assert_impl_one!(((),): Qux);
+    #[cfg(not(docsrs))]
+    assert_not_impl_any!(((),): Qux);
+    assert_impl_one!(((), ()): Qux);
+    assert_not_impl_any!(((), (), ()): Qux);
+    assert_impl_one!(((), (), (), ()): Qux);
+}
@@ -0,0 +1,76 @@
+#![allow(missing_docs, dead_code)]
+#![cfg_attr(docsrs, feature(rustdoc_internals))]
+
+use static_assertions::{assert_impl_one, assert_not_impl_any};
+use variadics_please::{all_tuples, all_tuples_with_size};
+
+trait Foo {}
+
+macro_rules! foo {
+    ($(#[$meta: meta])* $($t: ident),* $(,)?) => {
+        $(#[$meta])*
+        impl<$($t),*> Foo for ($($t,)*) {}
+    };
+}
+
+all_tuples!(
+    #[cfg(all())]
+    #[doc(fake_variadic)]
+    #[allow(non_snake_case)]
+    foo,
+    0,
+    2,
+    T
+);
+all_tuples!(
+    #[cfg(any())]
+    foo,
+    3,
+    4,
+    T
+);
+
+trait Bar {
+    const SIZE: usize;
+}
+
+macro_rules! bar {
+    ($size: literal, $(#[$meta: meta])* $($t: ident),* $(,)?) => {
+        $(#[$meta])*
+        impl<$($t),*> Bar for ($($t,)*) {
+            const SIZE: usize = $size;
+        }
+    };
+}
+
+all_tuples_with_size!(
+    #[automatically_derived]
+    bar,
+    0,
+    2,
+    T
+);
+
+trait Baz {}
+
+trait Qux {}
+
+// the attributes are forwarded to every item of the template
+all_tuples!(#[cfg(any())] 0, 2, T => {
+    impl<#(#T),*> Baz for (#(#T,)*) {}
+    impl<#(#T),*> Qux for (#(#T,)*) {}
+});
+
+#[test]
+fn attributes_test() {
+    assert_impl_one!((): Foo);
+    assert_impl_one!(((), ()): Foo);
+    assert_not_impl_any!(((), (), ()): Foo);
+
+    assert_eq!(<((), ()) as Bar>::SIZE, 2);
+
+    assert_not_impl_any!((): Baz);
+    assert_not_impl_any!(((),): Baz);
+    assert_not_impl_any!((): Qux);
+    assert_not_impl_any!(((), ()): Qux);
+}
@@ -35,6 +35,23 @@ all_tuples_enumerated!(bar, 0, 2, T, U);
 // [4, 5]
 all_tuples_enumerated!(bar, 4, 5, T, U);
 
+trait Indices {
+    fn indices() -> Vec<usize>;
+}
+
+macro_rules! indices {
+    ($(($n: tt,)),* $(,)?) => {
+        impl Indices for [(); 0 $(+ { let _ = $n; 1 })*] {
+            fn indices() -> Vec<usize> {
+                vec![$($n),*]
+            }
+        }
+    };
+}
+
+// no base idents, only the indices
+all_tuples_enumerated!(indices, 0..=2,);
+
 #[test]
 fn basic_test() {
     // 0
@@ -69,4 +86,7 @@ fn basic_test() {
     assert_impl_one!((((),()), ((),()), ((),()), ((),()), ((),())): Bar);
     // no 6
     assert_not_impl_any!((((),()), ((),()), ((),()), ((),()), ((),()), ((),())): Bar);
+
+    assert_eq!(<[(); 0]>::indices(), vec![]);
+    assert_eq!(<[(); 2]>::indices(), vec![0, 1]);
 }
@@ -0,0 +1,52 @@
+#![allow(missing_docs, dead_code)]
+
+use static_assertions::{assert_impl_one, assert_not_impl_any};
+use variadics_please::{all_tuples, all_tuples_with_size};
+
+trait Foo {}
+
+macro_rules! impl_foo {
+    ([$($generics: tt)*], [$($where: tt)*], $($T: ident),*) => {
+        impl<$($generics)*> Foo for ($($T,)*) {}
+    };
+}
+
+all_tuples!(impl_foo, 0..=3, T: Clone + Into<(u8, u16)> + 'static);
+
+trait Bar {}
+
+macro_rules! impl_bar {
+    ([$($generics: tt)*], [$($where: tt)*], $(($T: ident, $U: ident, $u: ident)),*) => {
+        impl<$($T,)* $($U),*> Bar for ($(($T, $U),)*) $($where)* {}
+    };
+}
+
+all_tuples!(impl_bar, 1..=2, T:, U: Fn(u8) -> Vec<Vec<u8>>, u);
+
+trait Len {
+    const LEN: usize;
+}
+
+macro_rules! impl_len {
+    ($N: expr, [$($generics: tt)*], [$($where: tt)*], $($T: ident),*) => {
+        impl<$($generics)*> Len for ($($T,)*) {
+            const LEN: usize = $N;
+        }
+    };
+}
+
+all_tuples_with_size!(impl_len, 1..=3, T: Copy; repeat);
+
+#[test]
+fn bounds_test() {
+    assert_impl_one!((): Foo);
+    assert_impl_one!(((u8, u16), (u8, u16)): Foo);
+    assert_not_impl_any!((String,): Foo);
+    assert_not_impl_any!(((), (), (), ()): Foo);
+
+    assert_impl_one!(((u8, fn(u8) -> Vec<Vec<u8>>),): Bar);
+    assert_not_impl_any!(((u8, fn(u16) -> Vec<Vec<u8>>),): Bar);
+
+    assert_eq!(<(u8, u8, u8) as Len>::LEN, 3);
+    assert_not_impl_any!((String, String): Len);
+}
@@ -0,0 +1,75 @@
+#![allow(missing_docs, dead_code)]
+
+use static_assertions::{assert_impl_one, assert_not_impl_any};
+use variadics_please::all_tuples_chunked;
+
+trait Foo {
+    const LEN: usize;
+}
+
+macro_rules! impl_foo {
+    ($(($($T: ident,)*)),*) => {
+        impl<$($($T),*),*> Foo for ($(($($T,)*),)*) {
+            const LEN: usize = 0 $($(+ { let _ = stringify!($T); 1 })*)*;
+        }
+    };
+}
+
+all_tuples_chunked!(impl_foo, 2, 0..=5, T);
+
+trait Bar {
+    fn names() -> Vec<Vec<&'static str>>;
+}
+
+macro_rules! impl_bar {
+    ($Trait: ident, $(($(($P: ident, $p: ident),)*)),*) => {
+        impl<$($($P),*),*> $Trait for ($(($($P,)*),)*) {
+            fn names() -> Vec<Vec<&'static str>> {
+                vec![$(vec![$(stringify!($p)),*]),*]
+            }
+        }
+    };
+}
+
+all_tuples_chunked!(impl_bar, 3, [1, 4, 7], P, p; [Bar]);
+
+#[derive(Debug, Default, PartialEq)]
+struct Wide<T>(T);
+
+macro_rules! impl_wide {
+    ($(($($T: ident,)*)),*) => {
+        impl<$($($T: Default),*),*> Foo for Wide<($(($($T,)*),)*)> {
+            const LEN: usize = 0 $($(+ { let _ = stringify!($T); 1 })*)*;
+        }
+    };
+}
+
+all_tuples_chunked!(impl_wide, 12, 64..=64, T);
+
+#[test]
+fn chunked_test() {
+    assert_eq!(<() as Foo>::LEN, 0);
+    assert_eq!(<(((),),) as Foo>::LEN, 1);
+    assert_eq!(<(((), ()),) as Foo>::LEN, 2);
+    assert_eq!(<(((), ()), ((),)) as Foo>::LEN, 3);
+    assert_eq!(<(((), ()), ((), ()), ((),)) as Foo>::LEN, 5);
+    assert_not_impl_any!(((), ()): Foo);
+    assert_not_impl_any!((((), (), ()),): Foo);
+
+    assert_eq!(<((u8,),)>::names(), vec![vec!["p0"]]);
+    assert_eq!(
+        <((u8, u8, u8), (u8,))>::names(),
+        vec![vec!["p0", "p1", "p2"], vec!["p3"]]
+    );
+    assert_impl_one!(((u8, u8, u8), (u8, u8, u8), (u8,)): Bar);
+    assert_not_impl_any!(((u8, u8),): Bar);
+
+    type Chunk = (u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8);
+    type Tail = (u8, u8, u8, u8);
+    let wide = Wide::<(Chunk, Chunk, Chunk, Chunk, Chunk, Tail)>::default();
+    assert_eq!(wide, Wide::default());
+    assert_eq!(
+        <Wide<(Chunk, Chunk, Chunk, Chunk, Chunk, Tail)> as Foo>::LEN,
+        64
+    );
+}
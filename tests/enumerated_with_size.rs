@@ -0,0 +1,74 @@
+#![allow(missing_docs, dead_code)]
+#![cfg_attr(docsrs, feature(rustdoc_internals))]
+
+use static_assertions::{assert_impl_one, assert_not_impl_any};
+use variadics_please::all_tuples_enumerated_with_size;
+
+trait Foo {
+    const SIZE: usize;
+    fn indices(&self) -> Vec<usize>;
+}
+
+macro_rules! impl_foo {
+    ($size: literal, $(($n: tt, $t: ident)),*) => {
+        impl<$($t),*> Foo for ($($t,)*) {
+            const SIZE: usize = $size;
+            fn indices(&self) -> Vec<usize> {
+                vec![$($n),*]
+            }
+        }
+    };
+}
+
+// [0, 2]
+all_tuples_enumerated_with_size!(impl_foo, 0, 2, T);
+
+// no {3}
+
+// [4, 5]
+all_tuples_enumerated_with_size!(impl_foo, 4..=5, T);
+
+trait Bar {
+    const SIZE: usize;
+}
+
+macro_rules! impl_bar {
+    ($size: literal, $(#[$meta:meta])* $(($n: tt, $t: ident)),*) => {
+        $(#[$meta])*
+        impl<$($t),*> Bar for ($($t,)*) {
+            const SIZE: usize = $size;
+        }
+    };
+}
+
+// no {1}
+all_tuples_enumerated_with_size!(
+    #[doc(fake_variadic)]
+    impl_bar,
+    2,
+    3,
+    T
+);
+
+#[test]
+fn enumerated_with_size_test() {
+    assert_eq!(<() as Foo>::SIZE, 0);
+    assert_eq!(().indices(), vec![]);
+    assert_eq!(<((),) as Foo>::SIZE, 1);
+    assert_eq!(((),).indices(), vec![0]);
+    assert_impl_one!(((), ()): Foo);
+    assert_eq!(<((), ()) as Foo>::SIZE, 2);
+    assert_eq!(((), ()).indices(), vec![0, 1]);
+    assert_not_impl_any!(((), (), ()): Foo);
+    assert_eq!(<((), (), (), ()) as Foo>::SIZE, 4);
+    assert_eq!(((), (), (), (), ()).indices(), vec![0, 1, 2, 3, 4]);
+    assert_not_impl_any!(((), (), (), (), (), ()): Foo);
+
+    // only impl for (T,) with `docsrs`
+    #[cfg(docsrs)]
+    assert_impl_one!(((),): Bar);
+    #[cfg(not(docsrs))]
+    assert_not_impl_any!(((),): Bar);
+    assert_eq!(<((), ()) as Bar>::SIZE, 2);
+    assert_eq!(<((), (), ()) as Bar>::SIZE, 3);
+}
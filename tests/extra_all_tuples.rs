@@ -0,0 +1,70 @@
+#![allow(missing_docs, dead_code)]
+#![cfg_attr(docsrs, feature(rustdoc_internals))]
+
+use static_assertions::{assert_impl_one, assert_not_impl_any};
+use variadics_please::{all_tuples, all_tuples_enumerated, all_tuples_with_size};
+
+trait Foo {}
+trait Bar {}
+
+macro_rules! impl_marker {
+    ($Trait:path, $($t: ident),* $(,)?) => {
+        impl<$($t),*> $Trait for ($($t,)*) {}
+    };
+}
+
+// [0, 2]
+all_tuples!(impl_marker, 0..=2, T; [Foo]);
+// [1, 3]
+all_tuples!(impl_marker, 1..=3, T; {Bar,});
+
+trait Borrowed<'w> {
+    fn first(&self) -> Option<&'w str>;
+}
+
+macro_rules! impl_borrowed {
+    ($lt:lifetime, $(($n:tt, $t: ident)),*) => {
+        impl<$lt, $($t: AsRef<str>),*> Borrowed<$lt> for ($(&$lt $t,)*) {
+            #[allow(unused_variables)]
+            fn first(&self) -> Option<&$lt str> {
+                None$(.or(Some(self.$n.as_ref())))*
+            }
+        }
+    };
+}
+
+all_tuples_enumerated!(impl_borrowed, 0..=2, T; ['w]);
+
+trait Sized_ {
+    const SIZE: usize;
+}
+
+macro_rules! impl_sized {
+    ($Trait:ident, $size:literal, $(#[$meta:meta])* $($t: ident),*) => {
+        $(#[$meta])*
+        impl<$($t),*> $Trait for ($($t,)*) {
+            const SIZE: usize = $size;
+        }
+    };
+}
+
+all_tuples_with_size!(#[doc(fake_variadic)] impl_sized, 0..=2, T; [Sized_]);
+
+#[test]
+fn extra_test() {
+    assert_impl_one!((): Foo);
+    assert_impl_one!(((), ()): Foo);
+    assert_not_impl_any!(((), (), ()): Foo);
+
+    assert_not_impl_any!((): Bar);
+    assert_impl_one!(((),): Bar);
+    assert_impl_one!(((), (), ()): Bar);
+
+    assert_eq!(().first(), None);
+    assert_eq!((&"a",).first(), Some("a"));
+    assert_eq!((&"a", &"b").first(), Some("a"));
+
+    assert_eq!(<() as Sized_>::SIZE, 0);
+    assert_eq!(<((),) as Sized_>::SIZE, 1);
+    assert_eq!(<((), ()) as Sized_>::SIZE, 2);
+}
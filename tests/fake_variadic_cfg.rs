@@ -0,0 +1,88 @@
+#![allow(missing_docs, dead_code)]
+#![cfg_attr(docsrs, feature(rustdoc_internals))]
+
+use static_assertions::{assert_impl_one, assert_not_impl_any};
+use variadics_please::{all_tuples, variadic};
+
+trait Foo {}
+
+macro_rules! foo {
+    ($(#[$meta: meta])* $($t: ident),* $(,)?) => {
+        $(#[$meta])*
+        impl<$($t),*> Foo for ($($t,)*) {}
+    };
+}
+
+// no {1}, and the extra impl for it only exists with the custom predicate
+all_tuples!(
+    #[doc(fake_variadic, cfg = any())]
+    foo,
+    2,
+    3,
+    T
+);
+
+trait Bar {}
+
+#[variadic(range = 2..=3, over = T)]
+#[doc(fake_variadic, cfg = all(docsrs, not(docsrs)))]
+impl<T> Bar for (T,) {}
+
+trait Baz {}
+
+macro_rules! baz {
+    ($(#[$meta: meta])* $($t: ident),* $(,)?) => {
+        $(#[$meta])*
+        impl<$($t),*> Baz for ($($t,)*) {}
+    };
+}
+
+// a trailing comma after the predicate
+all_tuples!(
+    #[doc(fake_variadic, cfg = any(),)]
+    baz,
+    2,
+    2,
+    T
+);
+
+trait Qux {}
+
+#[variadic(range = 2..=2, over = T)]
+#[doc(fake_variadic, cfg = any(),)]
+impl<T> Qux for (T,) {}
+
+trait Quux {}
+
+macro_rules! quux {
+    ($(#[$meta: meta])* $($t: ident),* $(,)?) => {
+        $(#[$meta])*
+        impl<$($t),*> Quux for ($($t,)*) {}
+    };
+}
+
+// a name-value predicate, like `feature = "docs"`
+all_tuples!(
+    #[doc(fake_variadic, cfg = target_os = "none")]
+    quux,
+    2,
+    2,
+    T
+);
+
+#[test]
+fn fake_variadic_cfg_test() {
+    assert_not_impl_any!(((),): Foo);
+    assert_impl_one!(((), ()): Foo);
+    assert_impl_one!(((), (), ()): Foo);
+
+    assert_not_impl_any!(((),): Bar);
+    assert_impl_one!(((), ()): Bar);
+
+    assert_not_impl_any!(((),): Baz, Qux);
+    assert_impl_one!(((), ()): Baz);
+    assert_impl_one!(((), ()): Qux);
+
+    assert_not_impl_any!(((),): Quux);
+    assert_impl_one!(((), ()): Quux);
+}
@@ -0,0 +1,76 @@
+#![allow(missing_docs, dead_code)]
+
+use static_assertions::{assert_impl_one, assert_not_impl_any};
+use variadics_please::{all_tuples, all_tuples_with_size};
+
+macro_rules! foo_tuples {
+    ($max:expr) => {
+        all_tuples!(foo, 0..=$max - 1, T);
+    };
+}
+
+macro_rules! bar_tuples {
+    ($range:expr) => {
+        all_tuples!(bar, $range, T);
+    };
+}
+
+macro_rules! baz_tuples {
+    ($start:literal, $end:expr) => {
+        all_tuples_with_size!(baz, $start, $end * 2, T);
+    };
+}
+
+trait Foo {}
+
+macro_rules! foo {
+    ($($t: ident),* $(,)?) => {
+        impl<$($t),*> Foo for ($($t,)*) {}
+    };
+}
+
+// [0, 3]
+foo_tuples!(4);
+
+trait Bar {}
+
+macro_rules! bar {
+    ($($t: ident),* $(,)?) => {
+        impl<$($t),*> Bar for ($($t,)*) {}
+    };
+}
+
+// [1, 2]
+bar_tuples!(1..(1 + 2));
+
+trait Baz {
+    const SIZE: usize;
+}
+
+macro_rules! baz {
+    ($size: literal, $($t: ident),* $(,)?) => {
+        impl<$($t),*> Baz for ($($t,)*) {
+            const SIZE: usize = $size;
+        }
+    };
+}
+
+// [3, 4]
+baz_tuples!(3, 4 / 2);
+
+#[test]
+fn forwarded_test() {
+    assert_impl_one!((): Foo);
+    assert_impl_one!(((), (), ()): Foo);
+    assert_not_impl_any!(((), (), (), ()): Foo);
+
+    assert_not_impl_any!((): Bar);
+    assert_impl_one!(((),): Bar);
+    assert_impl_one!(((), ()): Bar);
+    assert_not_impl_any!(((), (), ()): Bar);
+
+    assert_not_impl_any!(((), ()): Baz);
+    assert_eq!(<((), (), ()) as Baz>::SIZE, 3);
+    assert_eq!(<((), (), (), ()) as Baz>::SIZE, 4);
+    assert_not_impl_any!(((), (), (), (), ()): Baz);
+}
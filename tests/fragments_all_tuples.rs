@@ -0,0 +1,60 @@
+#![allow(missing_docs, dead_code)]
+#![cfg_attr(docsrs, feature(rustdoc_internals))]
+
+use static_assertions::{assert_impl_one, assert_not_impl_any};
+use variadics_please::{all_tuples, all_tuples_with_size};
+
+trait Foo {
+    fn names() -> (&'static str, &'static str, &'static str);
+}
+
+macro_rules! impl_foo {
+    ($Tuple: ty, $pat: pat, $indices: tt, $($t: ident),*) => {
+        impl<$($t),*> Foo for $Tuple {
+            fn names() -> (&'static str, &'static str, &'static str) {
+                (stringify!($Tuple), stringify!($pat), stringify!($indices))
+            }
+        }
+    };
+}
+
+all_tuples!(impl_foo, 0..=2, T; fragments);
+
+trait Fields {
+    const LEN: usize;
+    fn indices() -> Vec<usize>;
+    fn into_vec(self) -> Vec<u8>;
+}
+
+macro_rules! impl_fields {
+    ($n: literal, $Tuple: ty, ($($t: ident,)*), [$($i: tt),*], $(#[$meta: meta])* $(($p: ident, $q: ident)),*) => {
+        $(#[$meta])*
+        impl<$($p: Into<u8>),*> Fields for $Tuple {
+            const LEN: usize = $n;
+            fn indices() -> Vec<usize> {
+                vec![$($i),*]
+            }
+            fn into_vec(self) -> Vec<u8> {
+                let ($($t,)*) = self;
+                vec![$($t.into()),*]
+            }
+        }
+    };
+}
+
+all_tuples_with_size!(#[doc(fake_variadic)] impl_fields, 0..=3, P, q; fragments);
+
+#[test]
+fn fragments_test() {
+    assert_eq!(<()>::names(), ("()", "()", "[]"));
+    assert_eq!(<((),)>::names(), ("(T0,)", "(t0,)", "[0]"));
+    assert_eq!(<((), ())>::names(), ("(T0, T1,)", "(t0, t1,)", "[0, 1]"));
+
+    assert_impl_one!((u8,): Fields);
+    assert_eq!(<(u8,) as Fields>::LEN, 1);
+    assert_eq!(<(u8,)>::indices(), vec![0]);
+    assert_eq!((1u8,).into_vec(), vec![1]);
+    assert_eq!(<(u8, u8, u8)>::indices(), vec![0, 1, 2]);
+    assert_eq!((1u8, 2u8, 3u8).into_vec(), vec![1, 2, 3]);
+    assert_not_impl_any!((u8, u8, u8, u8): Fields);
+}
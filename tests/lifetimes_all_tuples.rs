@@ -0,0 +1,57 @@
+#![allow(missing_docs, dead_code)]
+
+use static_assertions::{assert_impl_one, assert_not_impl_any};
+use variadics_please::{all_tuples, all_tuples_enumerated};
+
+trait Borrowed {
+    type Owned;
+    fn cloned(&self) -> Self::Owned;
+}
+
+macro_rules! impl_borrowed {
+    ([$($generics: tt)*], [$($where: tt)*], $(($a: lifetime, $T: ident, $t: ident)),*) => {
+        impl<$($generics)*> Borrowed for ($(&$a $T,)*) $($where)* {
+            type Owned = ($($T,)*);
+            #[allow(clippy::unused_unit)]
+            fn cloned(&self) -> Self::Owned {
+                let ($($t,)*) = *self;
+                ($($t.clone(),)*)
+            }
+        }
+    };
+}
+
+all_tuples!(impl_borrowed, 0..=3, 'a, T: Clone + 'a, t);
+
+trait Flatten {
+    const TOTAL: usize;
+    fn flatten(&self) -> Vec<u8>;
+}
+
+macro_rules! impl_flatten {
+    ([$($generics: tt)*], [$($where: tt)*], $(($n: tt, $N: ident)),*) => {
+        impl<$($generics)*> Flatten for ($([u8; $N],)*) {
+            const TOTAL: usize = 0 $(+ $N)*;
+            fn flatten(&self) -> Vec<u8> {
+                let mut out = Vec::new();
+                $(out.extend_from_slice(&self.$n);)*
+                out
+            }
+        }
+    };
+}
+
+all_tuples_enumerated!(impl_flatten, 1..=3, const N: usize);
+
+#[test]
+fn lifetimes_test() {
+    assert_eq!(().cloned(), ());
+    let (a, b) = (String::from("a"), 2u8);
+    assert_eq!((&a, &b).cloned(), (String::from("a"), 2));
+    assert_impl_one!((&'static u8, &'static u16, &'static u32): Borrowed);
+    assert_not_impl_any!((&'static u8, &'static u8, &'static u8, &'static u8): Borrowed);
+
+    assert_eq!(<([u8; 2], [u8; 1]) as Flatten>::TOTAL, 3);
+    assert_eq!(([1, 2], [3], [4, 5]).flatten(), vec![1, 2, 3, 4, 5]);
+    assert_not_impl_any!((u8,): Flatten);
+}
@@ -0,0 +1,46 @@
+#![allow(missing_docs, dead_code)]
+
+use variadics_please::{all_tuples, all_tuples_enumerated_with_size};
+
+trait Sum {
+    fn sum(self) -> usize;
+}
+
+all_tuples!(0..=3, T, t; mixed_site => {
+    impl<#(#T: Into<usize>),*> Sum for (#(#T,)*) {
+        fn sum(self) -> usize {
+            let (#(#t,)*) = self;
+            // The generated `t0` has mixed site hygiene, so it isn't shadowed by this one.
+            let t0: usize = 0;
+            t0 #(+ #t.into())*
+        }
+    }
+});
+
+trait Len {
+    const LEN: usize;
+    fn last(&self) -> Option<usize>;
+}
+
+macro_rules! impl_len {
+    ($n: literal, $(($i: tt, $t: ident)),*) => {
+        impl<$($t),*> Len for ($($t,)*) {
+            const LEN: usize = $n;
+            fn last(&self) -> Option<usize> {
+                None$(.or(Some($i)))*
+            }
+        }
+    };
+}
+
+all_tuples_enumerated_with_size!(impl_len, 0..=2, T; mixed_site);
+
+#[test]
+fn mixed_site_test() {
+    assert_eq!(().sum(), 0);
+    assert_eq!((1u8, 2u8, 3u8).sum(), 6);
+
+    assert_eq!(<() as Len>::LEN, 0);
+    assert_eq!(<((), ()) as Len>::LEN, 2);
+    assert_eq!(((), ()).last(), Some(0));
+}
@@ -0,0 +1,45 @@
+#![allow(missing_docs, dead_code)]
+
+use variadics_please::{all_tuples, all_tuples_with_size};
+
+macro_rules! impl_join {
+    ($N: expr, $Join: ident, $join: ident, $(($T: ident, $t: ident)),*) => {
+        pub struct $Join<$($T),*>($($T,)*);
+
+        impl<$($T),*> $Join<$($T),*> {
+            pub const LENGTH: usize = $N;
+        }
+
+        pub fn $join<$($T),*>($($t: $T),*) -> $Join<$($T),*> {
+            $Join($($t,)*)
+        }
+    };
+}
+
+all_tuples_with_size!(impl_join, 1..=3, T, t; names = ["Join#N", "join#N"], mixed_site);
+
+trait Name {
+    const NAME: &'static str;
+}
+
+macro_rules! impl_name {
+    ($Name: ident, $($T: ident),*) => {
+        impl<$($T),*> Name for ($($T,)*) {
+            const NAME: &'static str = stringify!($Name);
+        }
+    };
+}
+
+all_tuples!(impl_name, 0..=2, T; names = ["Tuple#N"]);
+
+#[test]
+fn names_test() {
+    let Join1(a) = join1(1);
+    assert_eq!(a, 1);
+    let Join3(a, b, c) = join3(1, "2", 3.0);
+    assert_eq!((a, b, c), (1, "2", 3.0));
+    assert_eq!(Join2::<u8, u8>::LENGTH, 2);
+
+    assert_eq!(<()>::NAME, "Tuple0");
+    assert_eq!(<(u8, u8)>::NAME, "Tuple2");
+}
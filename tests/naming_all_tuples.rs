@@ -0,0 +1,115 @@
+#![allow(missing_docs, dead_code)]
+
+use core::marker::PhantomData;
+use variadics_please::{all_tuples, all_tuples_enumerated, variadic};
+
+trait Names {
+    fn names() -> Vec<&'static str>;
+}
+
+macro_rules! impl_names {
+    ($($t: ident),*) => {
+        impl<$($t),*> Names for ($($t,)*) {
+            fn names() -> Vec<&'static str> {
+                vec![$(stringify!($t)),*]
+            }
+        }
+    };
+}
+
+all_tuples!(impl_names, 0..=3, T; separator = "_", one_based);
+
+struct Padded<T>(T);
+
+trait PaddedNames {
+    fn names() -> Vec<&'static str>;
+}
+
+macro_rules! impl_padded_names {
+    ($(($_n: tt, $t: ident, $u: ident)),*) => {
+        impl<$($t),*> PaddedNames for Padded<($($t,)*)> {
+            fn names() -> Vec<&'static str> {
+                vec![$(concat!(stringify!($t), stringify!($u))),*]
+            }
+        }
+    };
+}
+
+all_tuples_enumerated!(impl_padded_names, 0..=11, T, u; zero_pad);
+
+struct Alphabetic<T>(T);
+
+trait AlphabeticNames {
+    fn names() -> Vec<&'static str>;
+}
+
+macro_rules! impl_alphabetic_names {
+    ($(($t: ident, $u: ident)),*) => {
+        impl<$($t),*> AlphabeticNames for Alphabetic<($($t,)*)> {
+            fn names() -> Vec<&'static str> {
+                vec![$(concat!(stringify!($t), stringify!($u))),*]
+            }
+        }
+    };
+}
+
+all_tuples!(impl_alphabetic_names, 0..=28, T, u; alphabetic);
+
+struct Variadic<T>(T);
+
+#[variadic(range = 1..=2, over = T, one_based)]
+impl<T> Names for Variadic<(T,)> {
+    fn names() -> Vec<&'static str> {
+        let mut names = Vec::new();
+        variadic_for!(_t in (PhantomData::<T>,) => names.push(stringify!(T)));
+        names
+    }
+}
+
+#[test]
+fn naming_test() {
+    assert_eq!(<()>::names(), Vec::<&str>::new());
+    assert_eq!(<((), (), ())>::names(), vec!["T_1", "T_2", "T_3"]);
+    assert_eq!(<Variadic<((), ())>>::names(), vec!["T1", "T2"]);
+
+    assert_eq!(
+        <Padded<((), (), ())>>::names(),
+        vec!["T00u00", "T01u01", "T02u02"]
+    );
+    assert_eq!(<Padded<((),)>>::names(), vec!["T00u00"]);
+
+    assert_eq!(<Alphabetic<((), ())>>::names(), vec!["Aa", "Bb"]);
+    let names = <Alphabetic<(
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+    )>>::names();
+    assert_eq!(names[25], "Zz");
+    assert_eq!(names[26], "AAaa");
+    assert_eq!(names[27], "ABab");
+}
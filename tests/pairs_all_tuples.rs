@@ -0,0 +1,51 @@
+#![allow(missing_docs, dead_code)]
+
+use static_assertions::{assert_impl_one, assert_not_impl_any};
+use variadics_please::all_tuple_pairs;
+
+trait Convert<Out> {
+    const SIZES: (usize, usize);
+}
+
+macro_rules! impl_convert {
+    ($n: literal, [$($A: ident),*], $m: literal, [$($B: ident),*]) => {
+        impl<$($A,)* $($B),*> Convert<($($B,)*)> for ($($A,)*) {
+            const SIZES: (usize, usize) = ($n, $m);
+        }
+    };
+}
+
+// [0, 2] x [1, 2]
+all_tuple_pairs!(impl_convert, 0..=2, A; 1..=2, B);
+
+trait Pipeline<Out> {
+    fn names() -> (Vec<&'static str>, Vec<&'static str>);
+}
+
+macro_rules! impl_pipeline {
+    ($Trait: ident, $n: literal, [$(($I: ident, $i: ident)),*], $m: literal, [$($O: ident),*]) => {
+        impl<$($I,)* $($O),*> $Trait<($($O,)*)> for ($($I,)*) {
+            fn names() -> (Vec<&'static str>, Vec<&'static str>) {
+                (vec![$(stringify!($i)),*], vec![$(stringify!($O)),*])
+            }
+        }
+    };
+}
+
+all_tuple_pairs!(impl_pipeline, [1, 3], In, i; 0..2, Out; [Pipeline]; one_based);
+
+#[test]
+fn pairs_test() {
+    assert_eq!(<() as Convert<((),)>>::SIZES, (0, 1));
+    assert_eq!(<((), ()) as Convert<((), ())>>::SIZES, (2, 2));
+    assert_impl_one!(((),): Convert<((), ())>);
+    assert_not_impl_any!((): Convert<()>);
+    assert_not_impl_any!(((), (), ()): Convert<((),)>);
+
+    assert_eq!(<((),) as Pipeline<()>>::names(), (vec!["i1"], vec![]));
+    assert_eq!(
+        <((), (), ()) as Pipeline<((),)>>::names(),
+        (vec!["i1", "i2", "i3"], vec!["Out1"])
+    );
+    assert_not_impl_any!(((), ()): Pipeline<()>);
+}
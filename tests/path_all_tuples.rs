@@ -0,0 +1,79 @@
+#![allow(missing_docs, dead_code)]
+
+use static_assertions::{assert_impl_one, assert_not_impl_any};
+use variadics_please::{all_tuples, all_tuples_enumerated, all_tuples_with_size};
+
+trait Foo {}
+
+mod impls {
+    macro_rules! impl_foo {
+        ($($t: ident),* $(,)?) => {
+            impl<$($t),*> $crate::Foo for ($($t,)*) {}
+        };
+    }
+    pub(crate) use impl_foo;
+
+    macro_rules! impl_baz {
+        ($(($_: literal, $t: ident)),* $(,)?) => {
+            impl<$($t),*> $crate::Baz for ($($t,)*) {}
+        };
+    }
+    pub(crate) use impl_baz;
+
+    pub(crate) mod nested {
+        super::super::all_tuples!(super::impl_foo, 0..=1, T);
+    }
+}
+
+all_tuples!(crate::impls::impl_foo, 2..=3, T);
+
+trait Bar {
+    const SIZE: usize;
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_bar {
+    ($size: literal, $($t: ident),* $(,)?) => {
+        impl<$($t),*> $crate::Bar for ($($t,)*) {
+            const SIZE: usize = $size;
+        }
+    };
+}
+
+/// Like an exported macro of a crate would.
+macro_rules! impl_all_bar {
+    ($range:expr) => {
+        all_tuples_with_size!($crate::__impl_bar, $range, T);
+    };
+}
+
+impl_all_bar!(0..=2);
+
+trait Baz {}
+
+/// Forwards the callback as a `path` fragment.
+macro_rules! impl_all_baz {
+    ($callback:path) => {
+        all_tuples_enumerated!($callback, 1..=2, T);
+    };
+}
+
+impl_all_baz!(impls::impl_baz);
+
+#[test]
+fn path_test() {
+    assert_impl_one!((): Foo);
+    assert_impl_one!(((),): Foo);
+    assert_impl_one!(((), ()): Foo);
+    assert_impl_one!(((), (), ()): Foo);
+    assert_not_impl_any!(((), (), (), ()): Foo);
+
+    assert_eq!(<() as Bar>::SIZE, 0);
+    assert_eq!(<((), ()) as Bar>::SIZE, 2);
+    assert_not_impl_any!(((), (), ()): Bar);
+
+    assert_not_impl_any!((): Baz);
+    assert_impl_one!(((),): Baz);
+    assert_impl_one!(((), ()): Baz);
+}
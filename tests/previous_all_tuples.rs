@@ -0,0 +1,58 @@
+#![allow(missing_docs, dead_code)]
+#![cfg_attr(docsrs, feature(rustdoc_internals))]
+
+use static_assertions::assert_not_impl_any;
+use variadics_please::{all_tuples_enumerated, all_tuples_with_size};
+
+trait Pop {
+    type Init;
+    fn pop(self) -> Self::Init;
+}
+
+macro_rules! impl_pop {
+    ([$(($i: tt, $P: ident)),*], $(($_j: tt, $T: ident)),*) => {
+        impl<$($T),*> Pop for ($($T,)*) {
+            type Init = ($($P,)*);
+            #[allow(clippy::unused_unit)]
+            fn pop(self) -> Self::Init {
+                ($(self.$i,)*)
+            }
+        }
+    };
+}
+
+all_tuples_enumerated!(impl_pop, 1..=4, T; previous);
+
+trait Len {
+    const LEN: usize;
+}
+
+impl Len for () {
+    const LEN: usize = 0;
+}
+
+macro_rules! impl_len {
+    ($n: literal, $(#[$meta: meta])* [$($P: ident),*], $($T: ident),*) => {
+        $(#[$meta])*
+        impl<$($T),*> Len for ($($T,)*)
+        where
+            ($($P,)*): Len,
+        {
+            const LEN: usize = $n;
+        }
+    };
+}
+
+all_tuples_with_size!(#[doc(fake_variadic)] impl_len, 1..=3, T; previous);
+
+#[test]
+fn previous_test() {
+    assert_eq!((1,).pop(), ());
+    assert_eq!((1, 2).pop(), (1,));
+    assert_eq!((1, 2, 3, 4).pop(), (1, 2, 3));
+    assert_not_impl_any!((u8, u8, u8, u8, u8): Pop);
+
+    assert_eq!(<((),) as Len>::LEN, 1);
+    assert_eq!(<((), (), ()) as Len>::LEN, 3);
+    assert_not_impl_any!(((), (), (), ()): Len);
+}
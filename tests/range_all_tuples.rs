@@ -0,0 +1,67 @@
+#![allow(missing_docs, dead_code)]
+
+use static_assertions::{assert_impl_one, assert_not_impl_any};
+use variadics_please::{all_tuples, all_tuples_enumerated, all_tuples_with_size};
+
+trait Foo {}
+
+macro_rules! foo {
+    ($($t: ident),* $(,)?) => {
+        impl<$($t),*> Foo for ($($t,)*) {}
+    };
+}
+
+// [0, 2]
+all_tuples!(foo, 0..=2, T);
+
+// no {3}
+
+// [4, 5]
+all_tuples!(foo, 4..6, T);
+
+trait Bar {}
+
+macro_rules! bar {
+    ($(($_: literal, $t: ident)),* $(,)?) => {
+        impl<$($t),*> Bar for ($($t,)*) {}
+    };
+}
+
+// [1, 2]
+all_tuples_enumerated!(bar, 1..3, T);
+
+trait Baz {
+    const SIZE: usize;
+}
+
+macro_rules! baz {
+    ($size: literal, $($t: ident),* $(,)?) => {
+        impl<$($t),*> Baz for ($($t,)*) {
+            const SIZE: usize = $size;
+        }
+    };
+}
+
+// [3]
+all_tuples_with_size!(baz, 3..=3, T);
+
+#[test]
+fn range_test() {
+    assert_impl_one!((): Foo);
+    assert_impl_one!(((),): Foo);
+    assert_impl_one!(((), ()): Foo);
+    assert_not_impl_any!(((), (), ()): Foo);
+    assert_impl_one!(((), (), (), ()): Foo);
+    assert_impl_one!(((), (), (), (), ()): Foo);
+    assert_not_impl_any!(((), (), (), (), (), ()): Foo);
+
+    assert_not_impl_any!((): Bar);
+    assert_impl_one!(((),): Bar);
+    assert_impl_one!(((), ()): Bar);
+    assert_not_impl_any!(((), (), ()): Bar);
+
+    assert_not_impl_any!(((), ()): Baz);
+    assert_impl_one!(((), (), ()): Baz);
+    assert_eq!(<((), (), ()) as Baz>::SIZE, 3);
+    assert_not_impl_any!(((), (), (), ()): Baz);
+}
@@ -0,0 +1,49 @@
+#![allow(missing_docs, dead_code)]
+
+use static_assertions::assert_not_impl_any;
+use variadics_please::{all_tuples_enumerated, all_tuples_with_size};
+
+trait FromArray<T, const N: usize> {
+    fn from_array(array: [T; N]) -> Self;
+}
+
+macro_rules! impl_from_array {
+    ($Tuple: ty, $pat: pat, $indices: tt, $(($n: tt, $T: ident)),*) => {
+        impl<T: Copy> FromArray<T, { $indices.len() }> for $Tuple {
+            fn from_array(array: [T; $indices.len()]) -> Self {
+                ($(array[$n],)*)
+            }
+        }
+    };
+}
+
+all_tuples_enumerated!(impl_from_array, 1..=3, T; repeat, fragments);
+
+trait Homogeneous {
+    const LENGTH: usize;
+    fn names() -> Vec<&'static str>;
+}
+
+macro_rules! impl_homogeneous {
+    ($N: expr, $(($T: ident, $u: ident)),*) => {
+        impl<T> Homogeneous for ($($T,)*) {
+            const LENGTH: usize = $N;
+            fn names() -> Vec<&'static str> {
+                vec![$(concat!(stringify!($T), stringify!($u))),*]
+            }
+        }
+    };
+}
+
+all_tuples_with_size!(impl_homogeneous, 1..=3, T, u; repeat);
+
+#[test]
+fn repeat_test() {
+    assert_eq!(<(u8,)>::from_array([1]), (1,));
+    assert_eq!(<(u8, u8, u8)>::from_array([1, 2, 3]), (1, 2, 3));
+
+    assert_eq!(<(u8,) as Homogeneous>::LENGTH, 1);
+    assert_eq!(<(u8, u8) as Homogeneous>::LENGTH, 2);
+    assert_eq!(<(u8, u8, u8)>::names(), vec!["Tu", "Tu", "Tu"]);
+    assert_not_impl_any!((u8, u16): Homogeneous);
+}
@@ -0,0 +1,51 @@
+#![allow(missing_docs, dead_code)]
+
+use static_assertions::assert_not_impl_any;
+use variadics_please::{all_tuples, all_tuples_enumerated};
+
+trait Reverse {
+    type Reversed;
+    fn reverse(self) -> Self::Reversed;
+}
+
+macro_rules! impl_reverse {
+    ($Tuple: ty, $pat: pat, $indices: tt, $(($n: tt, $T: ident)),*) => {
+        impl<$($T),*> Reverse for $Tuple {
+            type Reversed = ($($T,)*);
+            #[allow(clippy::unused_unit)]
+            fn reverse(self) -> Self::Reversed {
+                ($(self.$n,)*)
+            }
+        }
+    };
+}
+
+all_tuples_enumerated!(impl_reverse, 0..=3, T; reverse, fragments);
+
+trait Names {
+    fn names() -> Vec<&'static str>;
+}
+
+macro_rules! impl_names {
+    ($L: ident; $($T: ident),*) => {
+        impl<$L, $($T),*> Names for ($L, $($T,)*) {
+            fn names() -> Vec<&'static str> {
+                vec![stringify!($L), $(stringify!($T)),*]
+            }
+        }
+    };
+}
+
+all_tuples!(impl_names, 1..=3, T; reverse, split = head_tail);
+
+#[test]
+fn reverse_test() {
+    assert_eq!(().reverse(), ());
+    assert_eq!((1,).reverse(), (1,));
+    assert_eq!((1, "2").reverse(), ("2", 1));
+    assert_eq!((1, "2", 3.0).reverse(), (3.0, "2", 1));
+    assert_not_impl_any!((u8, u8, u8, u8): Reverse);
+
+    assert_eq!(<(u8,)>::names(), vec!["T0"]);
+    assert_eq!(<(u8, u8, u8)>::names(), vec!["T2", "T1", "T0"]);
+}
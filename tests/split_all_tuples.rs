@@ -0,0 +1,70 @@
+#![allow(missing_docs, dead_code)]
+#![cfg_attr(docsrs, feature(rustdoc_internals))]
+
+use static_assertions::{assert_impl_one, assert_not_impl_any};
+use variadics_please::{all_tuples, all_tuples_enumerated, all_tuples_with_size};
+
+trait Sum {
+    fn sum(&self) -> u32;
+}
+
+impl Sum for () {
+    fn sum(&self) -> u32 {
+        0
+    }
+}
+
+macro_rules! impl_sum {
+    ($(#[$meta: meta])* ($n: tt, $H: ident); $(($i: tt, $T: ident)),*) => {
+        $(#[$meta])*
+        impl<$H: Copy + Into<u32>, $($T: Copy + Into<u32>),*> Sum for ($H, $($T,)*) {
+            fn sum(&self) -> u32 {
+                self.$n.into() + ($(self.$i,)*).sum()
+            }
+        }
+    };
+}
+
+all_tuples_enumerated!(#[doc(fake_variadic)] impl_sum, 1..=3, T; split = head_tail);
+
+trait Last {
+    type Last;
+    const LEN: usize;
+}
+
+macro_rules! impl_last {
+    ($n: literal, $($T: ident),*; $L: ident) => {
+        impl<$($T,)* $L> Last for ($($T,)* $L,) {
+            type Last = $L;
+            const LEN: usize = $n;
+        }
+    };
+}
+
+all_tuples_with_size!(impl_last, 1..=3, T; split = init_last);
+
+trait Pairs {}
+
+macro_rules! impl_pairs {
+    ($(($P: ident, $p: ident)),*; ($L: ident, $l: ident)) => {
+        impl<$($P,)* $L> Pairs for ($($P,)* $L,) {}
+    };
+}
+
+all_tuples!(impl_pairs, 2..=2, P, p; split = init_last);
+
+#[test]
+fn split_test() {
+    assert_eq!((1u8,).sum(), 1);
+    assert_eq!((1u8, 2u16, 3u32).sum(), 6);
+    assert_not_impl_any!((u8, u8, u8, u8): Sum);
+
+    assert_eq!(<(u8, u16, u32) as Last>::LEN, 3);
+    let last: <(u8, u16, u32) as Last>::Last = 0u32;
+    assert_eq!(last, 0);
+    let last: <(u8,) as Last>::Last = 0u8;
+    assert_eq!(last, 0);
+
+    assert_not_impl_any!(((),): Pairs);
+    assert_impl_one!(((), ()): Pairs);
+}
@@ -0,0 +1,65 @@
+#![allow(missing_docs, dead_code)]
+
+use static_assertions::{assert_impl_one, assert_not_impl_any};
+use variadics_please::{all_permutations, all_subsets};
+
+struct A;
+struct B;
+struct C;
+
+trait Subset {
+    fn names() -> Vec<&'static str>;
+}
+
+macro_rules! impl_subset {
+    ($($T: ident),*) => {
+        impl Subset for ($($T,)*) {
+            fn names() -> Vec<&'static str> {
+                vec![$(stringify!($T)),*]
+            }
+        }
+    };
+}
+
+all_subsets!(impl_subset, [A, B, C]);
+
+trait Permutation {}
+
+macro_rules! impl_permutation {
+    ($($T: ident),*) => {
+        impl Permutation for ($($T,)*) {}
+    };
+}
+
+all_permutations!(impl_permutation, [A, B, C,]);
+
+trait Empty {}
+
+macro_rules! impl_empty {
+    ($($T: ident),*) => {
+        impl Empty for ($($T,)*) {}
+    };
+}
+
+all_permutations!(impl_empty, []);
+
+#[test]
+fn subsets_permutations_test() {
+    assert_eq!(<()>::names(), Vec::<&str>::new());
+    assert_eq!(<(B,)>::names(), vec!["B"]);
+    assert_eq!(<(A, C)>::names(), vec!["A", "C"]);
+    assert_eq!(<(A, B, C)>::names(), vec!["A", "B", "C"]);
+    assert_not_impl_any!((B, A): Subset);
+    assert_not_impl_any!((A, A): Subset);
+
+    assert_impl_one!((A, B, C): Permutation);
+    assert_impl_one!((A, C, B): Permutation);
+    assert_impl_one!((B, A, C): Permutation);
+    assert_impl_one!((B, C, A): Permutation);
+    assert_impl_one!((C, A, B): Permutation);
+    assert_impl_one!((C, B, A): Permutation);
+    assert_not_impl_any!((A, B): Permutation);
+    assert_not_impl_any!((): Permutation);
+
+    assert_impl_one!((): Empty);
+}
@@ -0,0 +1,78 @@
+#![allow(missing_docs, dead_code)]
+#![cfg_attr(docsrs, feature(rustdoc_internals))]
+
+use static_assertions::{assert_impl_one, assert_not_impl_any};
+use variadics_please::{all_tuples, all_tuples_enumerated, all_tuples_with_size};
+
+trait Foo {
+    const SIZE: usize;
+}
+
+// [0, 2]
+all_tuples!(0, 2, T => {
+    impl<#(#T),*> Foo for (#(#T,)*) {
+        const SIZE: usize = #N;
+    }
+});
+
+// no {3}
+
+// [4, 5]
+all_tuples_with_size!(4, 5, T => {
+    impl<#(#T),*> Foo for (#(#T,)*) {
+        const SIZE: usize = #N;
+    }
+});
+
+trait Bar {
+    fn indexed(&self) -> Vec<(usize, usize)>;
+}
+
+all_tuples_enumerated!(0, 3, T, t => {
+    impl<#(#T: Copy + Into<usize>),*> Bar for (#(#T,)*) {
+        fn indexed(&self) -> Vec<(usize, usize)> {
+            let (#(#t,)*) = *self;
+            vec![#((#i, #t.into())),*]
+        }
+    }
+});
+
+trait Baz {}
+
+all_tuples!(#[doc(fake_variadic)] 0, 2, T => {
+    #[allow(unused_parens)]
+    impl<#(#T),*> Baz for (#(#T,)*) {}
+});
+
+#[test]
+fn template_test() {
+    // 0
+    assert_impl_one!((): Foo);
+    assert_eq!(<() as Foo>::SIZE, 0);
+    // 1
+    assert_impl_one!(((),): Foo);
+    assert_eq!(<((),) as Foo>::SIZE, 1);
+    // 2
+    assert_impl_one!(((), ()): Foo);
+    assert_eq!(<((), ()) as Foo>::SIZE, 2);
+    // no 3
+    assert_not_impl_any!(((), (), ()): Foo);
+
+    // 4
+    assert_impl_one!(((), (), (), ()): Foo);
+    assert_eq!(<((), (), (), ()) as Foo>::SIZE, 4);
+    // 5
+    assert_impl_one!(((), (), (), (), ()): Foo);
+    assert_eq!(<((), (), (), (), ()) as Foo>::SIZE, 5);
+    // no 6
+    assert_not_impl_any!(((), (), (), (), (), ()): Foo);
+
+    assert_eq!(().indexed(), vec![]);
+    assert_eq!((7u8,).indexed(), vec![(0, 7)]);
+    assert_eq!((7u8, 8u8, 9u8).indexed(), vec![(0, 7), (1, 8), (2, 9)]);
+
+    assert_impl_one!((): Baz);
+    assert_impl_one!(((),): Baz);
+    assert_impl_one!(((), ()): Baz);
+    assert_not_impl_any!(((), (), ()): Baz);
+}
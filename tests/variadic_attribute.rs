@@ -0,0 +1,120 @@
+#![allow(missing_docs, dead_code)]
+#![cfg_attr(docsrs, feature(rustdoc_internals))]
+
+use static_assertions::{assert_impl_one, assert_not_impl_any};
+use variadics_please::variadic;
+
+trait Foo {
+    fn foo(&self) -> usize;
+    fn new() -> Self;
+}
+
+impl Foo for u8 {
+    fn foo(&self) -> usize {
+        *self as usize
+    }
+
+    fn new() -> Self {
+        1
+    }
+}
+
+// [0, 2]
+#[variadic(range = 0..=2, over = T)]
+impl<T: Foo> Foo for (T,) {
+    #[allow(unused_mut)]
+    fn foo(&self) -> usize {
+        let mut sum = 0;
+        variadic_for!(t in self => sum += t.foo());
+        sum
+    }
+
+    #[allow(clippy::unused_unit)]
+    fn new() -> Self {
+        (T::new(),)
+    }
+}
+
+// no {3}
+
+// [4, 5]
+#[variadic(range = 4..6, over = T)]
+impl<T> Foo for (T,)
+where
+    T: Foo,
+{
+    fn foo(&self) -> usize {
+        let mut sum = 0;
+        variadic_for!(t in self => sum += t.foo() * 10);
+        sum
+    }
+
+    fn new() -> Self {
+        (T::new(),)
+    }
+}
+
+trait Append {
+    type Out<Item>;
+    fn append<Item>(tup: Self, item: Item) -> Self::Out<Item>;
+}
+
+#[variadic(range = 0..=3, over = (P, p))]
+impl<P> Append for (P,) {
+    type Out<Item> = (P, Item);
+    fn append<Item>((p,): Self, item: Item) -> Self::Out<Item> {
+        (p, item)
+    }
+}
+
+trait Bar {}
+
+#[variadic(range = 0..=2, over = T)]
+#[doc(fake_variadic)]
+impl<T> Bar for (T,) {}
+
+trait Baz {}
+
+// no {1}
+#[variadic(range = 2..=3, over = T)]
+#[doc(fake_variadic)]
+impl<T> Baz for (T,) {}
+
+#[test]
+fn variadic_test() {
+    // 0
+    assert_eq!(().foo(), 0);
+    assert_eq!(<()>::new(), ());
+    // 1
+    assert_eq!((2u8,).foo(), 2);
+    assert_eq!(<(u8,)>::new(), (1,));
+    // 2
+    assert_eq!((2u8, 3u8).foo(), 5);
+    // no 3
+    assert_not_impl_any!((u8, u8, u8): Foo);
+
+    // 4
+    assert_eq!((1u8, 2u8, 3u8, 4u8).foo(), 100);
+    assert_eq!(<(u8, u8, u8, u8)>::new(), (1, 1, 1, 1));
+    // 5
+    assert_impl_one!((u8, u8, u8, u8, u8): Foo);
+    // no 6
+    assert_not_impl_any!((u8, u8, u8, u8, u8, u8): Foo);
+
+    assert_eq!(Append::append((), 1), (1,));
+    assert_eq!(Append::append((1,), 2), (1, 2));
+    assert_eq!(Append::append((1, 2, 3), 4), (1, 2, 3, 4));
+
+    assert_impl_one!((): Bar);
+    assert_impl_one!(((),): Bar);
+    assert_impl_one!(((), ()): Bar);
+    assert_not_impl_any!(((), (), ()): Bar);
+
+    // only impl for (T,) with `docsrs`
+    #[cfg(docsrs)]
+    assert_impl_one!(((),): Baz);
+    #[cfg(not(docsrs))]
+    assert_not_impl_any!(((),): Baz);
+    assert_impl_one!(((), ()): Baz);
+    assert_impl_one!(((), (), ()): Baz);
+}